@@ -0,0 +1,46 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc::day18::{Day18, FlatNumbers};
+use aoc::input::{Inputs, Source};
+use aoc::solution::Solution;
+
+const RUNS: u32 = 10;
+
+// Part 2 of day 18, adding every ordered pair of homework numbers, once
+// with each way of reducing. Run with `cargo bench --bench day18`.
+fn main() {
+    let input = Inputs::from_env().read(Day18::DAY, &Source::Day).unwrap();
+    let numbers = Day18::parse(&input).unwrap();
+
+    let stepwise = time(&numbers, FlatNumbers::reduce_stepwise);
+    let single_pass = time(&numbers, FlatNumbers::reduce);
+
+    println!("stepwise:    {:?} per run", stepwise);
+    println!("single pass: {:?} per run", single_pass);
+    println!(
+        "speedup:     {:.1}x",
+        stepwise.as_secs_f64() / single_pass.as_secs_f64()
+    );
+}
+
+fn time(numbers: &[FlatNumbers], reduce: fn(&mut FlatNumbers)) -> Duration {
+    let mut best = 0;
+    let start = Instant::now();
+
+    for _ in 0..RUNS {
+        for (i, a) in numbers.iter().enumerate() {
+            for (j, b) in numbers.iter().enumerate() {
+                if i != j {
+                    let mut sum = a.concat(b);
+                    reduce(&mut sum);
+                    best = best.max(sum.magnitude());
+                }
+            }
+        }
+    }
+
+    black_box(best);
+
+    start.elapsed() / RUNS
+}
@@ -0,0 +1,14 @@
+use aoc::day12::Day12;
+use aoc::input::{Inputs, Source};
+use aoc::solution::Solution;
+
+// usage: day12_dot [<input>|-] | dot -Tsvg > caves.svg
+fn main() {
+    let source = Source::from_arg(std::env::args().nth(1).as_deref());
+    let input = Inputs::from_env().read(Day12::DAY, &source).unwrap();
+
+    match Day12::parse(&input) {
+        Ok(cave_network) => print!("{}", cave_network.dot()),
+        Err(e) => eprintln!("{}", e),
+    }
+}
@@ -0,0 +1,46 @@
+use aoc::day12::{Day12, Policy, Revisits};
+use aoc::input::{Inputs, Source};
+use aoc::solution::Solution;
+
+// usage: day12_paths [<input>|-] [--twice] [--limit <n>]
+//
+// Prints every path the way the puzzle lists them, with one small cave
+// visited twice if `--twice` is given, stopping after <n> paths.
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut input_arg = None;
+    let mut revisits = Revisits::Never;
+    let mut limit = usize::MAX;
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--twice" => revisits = Revisits::OneTwice,
+            "--limit" => {
+                limit = args
+                    .next()
+                    .expect("--limit needs a number")
+                    .parse()
+                    .unwrap()
+            }
+            _ => input_arg = Some(arg.as_str()),
+        }
+    }
+
+    let source = Source::from_arg(input_arg);
+    let input = Inputs::from_env().read(Day12::DAY, &source).unwrap();
+
+    let cave_network = match Day12::parse(&input) {
+        Ok(cave_network) => cave_network,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    for path in cave_network.paths(&Policy::new(revisits)).take(limit) {
+        println!("{}", path.join(","));
+    }
+}
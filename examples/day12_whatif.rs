@@ -0,0 +1,37 @@
+use aoc::day12::{Day12, Policy, Revisits};
+use aoc::input::{Inputs, Source};
+use aoc::solution::Solution;
+
+// usage: day12_whatif [<input>|- [<times> [<forbidden cave>...]]]
+//
+// Counts paths visiting every small cave up to <times> times (2 by default)
+// and never going into any of the forbidden caves, next to the puzzle's own
+// rules with the same caves forbidden.
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    let source = Source::from_arg(args.get(1).map(String::as_str));
+    let input = Inputs::from_env().read(Day12::DAY, &source).unwrap();
+    let times = args.get(2).map_or(2, |times| times.parse().unwrap());
+    let forbidden = args.get(3..).unwrap_or_default();
+
+    let cave_network = match Day12::parse(&input) {
+        Ok(cave_network) => cave_network,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    for revisits in [Revisits::Never, Revisits::OneTwice, Revisits::UpTo(times)] {
+        let policy = forbidden
+            .iter()
+            .fold(Policy::new(revisits), |policy, name| policy.forbid(name));
+
+        println!(
+            "{:?}: {} paths",
+            revisits,
+            cave_network.count_paths(&policy)
+        );
+    }
+}
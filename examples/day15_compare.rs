@@ -0,0 +1,42 @@
+use std::time::Instant;
+
+use aoc::day15::{lowest_risk_path, Day15, Search, TiledCave};
+use aoc::input::{Inputs, Source};
+use aoc::solution::Solution;
+
+// usage: day15_compare [<input>|- [<times>]]
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    let source = Source::from_arg(args.get(1).map(String::as_str));
+    let input = Inputs::from_env().read(Day15::DAY, &source).unwrap();
+    let times = args.get(2).map_or(5, |times| times.parse().unwrap());
+
+    let risks = match Day15::parse(&input) {
+        Ok(risks) => risks,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    for times in [1, times] {
+        let cave = TiledCave::new(&risks, times);
+
+        for search in [Search::Dijkstra, Search::AStar] {
+            let start = Instant::now();
+            let path = lowest_risk_path(&cave, search);
+            let elapsed = start.elapsed();
+
+            println!(
+                "{}x {:?}: risk {}, expanded {} of {} nodes ({:?})",
+                times,
+                search,
+                path.cost,
+                path.expanded,
+                cave.len(),
+                elapsed
+            );
+        }
+    }
+}
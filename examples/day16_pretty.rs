@@ -0,0 +1,38 @@
+use aoc::day16::{Day16, Style};
+use aoc::input::{Inputs, Source};
+use aoc::solution::Solution;
+
+// usage: day16_pretty [<input>|- [--versions]]
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    let source = Source::from_arg(args.get(1).map(String::as_str));
+    let input = Inputs::from_env().read(Day16::DAY, &source).unwrap();
+    let versions = args.iter().any(|arg| arg == "--versions");
+
+    let packet = match Day16::parse(&input) {
+        Ok(packet) => packet,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    for style in [Style::SExpr, Style::Infix] {
+        let pretty = packet.pretty(style);
+
+        if versions {
+            println!("{}", pretty.with_versions());
+        } else {
+            println!("{}", pretty);
+        }
+    }
+
+    match packet.score() {
+        Ok(score) => println!("= {}", score),
+        Err(e) => match packet.big_score() {
+            Ok(score) => println!("= {} ({})", score, e),
+            Err(e) => eprintln!("{}", e),
+        },
+    }
+}
@@ -1,36 +1,13 @@
-use std::io::BufRead;
+use aoc::day01::Day1;
+use aoc::input::{Inputs, Source};
+use aoc::solution::{run_part2, Solution};
 
 fn main() {
-    let input = std::fs::File::open("inputs/1.txt").unwrap();
-    let lines = std::io::BufReader::new(input).lines();
+    let source = Source::from_arg(std::env::args().nth(1).as_deref());
+    let input = Inputs::from_env().read(Day1::DAY, &source).unwrap();
 
-    let mut increases: usize = 0;
-
-    let numbers: Vec<usize> = lines
-        .map(|line| line.unwrap().parse::<usize>().unwrap())
-        .collect();
-
-    let triples = numbers.windows(3);
-
-    let triples_sums: Vec<usize> = triples
-        .map(|triple| match triple {
-            [a, b, c] => a + b + c,
-            _ => panic!(),
-        })
-        .collect();
-
-    let pairs = triples_sums.windows(2);
-
-    for pair in pairs {
-        match pair {
-            [a, b] => {
-                if b > a {
-                    increases += 1;
-                }
-            }
-            _ => panic!(),
-        }
+    match run_part2::<Day1>(&input) {
+        Ok(answer) => println!("{}", answer),
+        Err(e) => eprintln!("{}", e),
     }
-
-    println!("{}", increases);
 }
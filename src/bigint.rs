@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, Mul};
+
+// each limb holds a base 2^32 digit, least significant first
+const LIMB_BITS: u32 = u32::BITS;
+
+/// An unsigned integer as big as it needs to be. Only what's needed to
+/// evaluate expressions without overflowing: adding, multiplying, comparing
+/// and printing.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    // never has zeros at the end, so every number has one representation
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// The value as a `usize`, if it fits in one.
+    pub fn to_usize(&self) -> Option<usize> {
+        // there are no zero limbs at the end, so any more than this is too big
+        if self.limbs.len() > (usize::BITS / LIMB_BITS) as usize {
+            return None;
+        }
+
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0, |n, limb| n << LIMB_BITS | *limb as usize),
+        )
+    }
+
+    fn trim(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+
+        self
+    }
+
+    // Divides in place by a small divisor and returns the remainder.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+
+        for limb in self.limbs.iter_mut().rev() {
+            let n = remainder << LIMB_BITS | *limb as u64;
+            *limb = (n / divisor as u64) as u32;
+            remainder = n % divisor as u64;
+        }
+
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+
+        remainder as u32
+    }
+}
+
+impl From<usize> for BigUint {
+    fn from(mut n: usize) -> Self {
+        let mut limbs = vec![];
+
+        while n != 0 {
+            limbs.push(n as u32);
+            n = n.checked_shr(LIMB_BITS).unwrap_or(0);
+        }
+
+        Self { limbs }
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let len = self.limbs.len().max(other.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0u64;
+
+        for i in 0..len {
+            let a = self.limbs.get(i).copied().unwrap_or(0) as u64;
+            let b = other.limbs.get(i).copied().unwrap_or(0) as u64;
+            let sum = a + b + carry;
+
+            limbs.push(sum as u32);
+            carry = sum >> LIMB_BITS;
+        }
+
+        limbs.push(carry as u32);
+
+        BigUint { limbs }.trim()
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        &self + &other
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        // schoolbook multiplication, which is plenty for a few hundred digits
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, b) in other.limbs.iter().enumerate() {
+                let n = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = n as u32;
+                carry = n >> LIMB_BITS;
+            }
+
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+
+        BigUint { limbs }.trim()
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        &self * &other
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // nine decimal digits at a time, least significant first
+        let mut n = self.clone();
+        let mut chunks = vec![];
+
+        while !n.is_zero() {
+            chunks.push(n.div_rem_small(1_000_000_000));
+        }
+
+        write!(f, "{}", chunks.pop().unwrap())?;
+
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:09}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let max = BigUint::from(usize::MAX);
+        let one = BigUint::one();
+
+        let sum = &max + &one;
+        assert_eq!(sum.to_usize(), None);
+        assert_eq!(sum.to_string(), (usize::MAX as u128 + 1).to_string());
+
+        let square = &max * &max;
+        assert_eq!(
+            square.to_string(),
+            (usize::MAX as u128 * usize::MAX as u128).to_string()
+        );
+
+        assert_eq!((&BigUint::from(1234) * &BigUint::zero()), BigUint::zero());
+        assert_eq!(BigUint::from(1234).to_usize(), Some(1234));
+    }
+
+    #[test]
+    fn ordering() {
+        let small = BigUint::from(5);
+        let big = &BigUint::from(usize::MAX) * &small;
+
+        assert!(small < big);
+        assert!(big > BigUint::from(usize::MAX));
+        assert_eq!(BigUint::zero().cmp(&BigUint::zero()), Ordering::Equal);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from(1_000_000_007).to_string(), "1000000007");
+
+        // 10^40, which has to carry across the nine digit chunks
+        let ten = BigUint::from(10);
+        let big = (0..40).fold(BigUint::one(), |n, _| &n * &ten);
+        assert_eq!(big.to_string(), format!("1{}", "0".repeat(40)));
+    }
+}
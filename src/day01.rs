@@ -0,0 +1,63 @@
+use crate::error::{parse_field, parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: usize = 1;
+
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| parse_field(Self::DAY, line, line))
+    }
+
+    fn part1(numbers: &Self::Input) -> Self::Part1 {
+        let mut increases: usize = 0;
+
+        let pairs = numbers.windows(2);
+
+        for pair in pairs {
+            match pair {
+                [left, right] => {
+                    if right > left {
+                        increases += 1;
+                    }
+                }
+                _ => panic!(),
+            }
+        }
+
+        increases
+    }
+
+    fn part2(numbers: &Self::Input) -> Self::Part2 {
+        let mut increases: usize = 0;
+
+        let triples = numbers.windows(3);
+
+        let triples_sums: Vec<usize> = triples
+            .map(|triple| match triple {
+                [a, b, c] => a + b + c,
+                _ => panic!(),
+            })
+            .collect();
+
+        let pairs = triples_sums.windows(2);
+
+        for pair in pairs {
+            match pair {
+                [a, b] => {
+                    if b > a {
+                        increases += 1;
+                    }
+                }
+                _ => panic!(),
+            }
+        }
+
+        increases
+    }
+}
@@ -0,0 +1,72 @@
+use crate::error::{parse_field, parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub struct Day2;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl Solution for Day2 {
+    const DAY: usize = 2;
+
+    type Input = Vec<Command>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            if let Some(rest) = line.strip_prefix("forward ") {
+                Ok(Command::Forward(parse_field(Self::DAY, line, rest)?))
+            } else if let Some(rest) = line.strip_prefix("down ") {
+                Ok(Command::Down(parse_field(Self::DAY, line, rest)?))
+            } else if let Some(rest) = line.strip_prefix("up ") {
+                Ok(Command::Up(parse_field(Self::DAY, line, rest)?))
+            } else {
+                Err(ParseError::new(
+                    Self::DAY,
+                    line,
+                    1,
+                    "expected forward, down or up",
+                ))
+            }
+        })
+    }
+
+    fn part1(commands: &Self::Input) -> Self::Part1 {
+        let mut horizontal_position = 0;
+        let mut depth = 0;
+
+        for command in commands {
+            match command {
+                Command::Forward(horizontal_move) => horizontal_position += horizontal_move,
+                Command::Down(depth_move) => depth += depth_move,
+                Command::Up(depth_move) => depth -= depth_move,
+            }
+        }
+
+        horizontal_position * depth
+    }
+
+    fn part2(commands: &Self::Input) -> Self::Part2 {
+        let mut horizontal_position = 0;
+        let mut depth = 0;
+        let mut aim = 0;
+
+        for command in commands {
+            match command {
+                Command::Forward(horizontal_move) => {
+                    horizontal_position += horizontal_move;
+                    depth += aim * horizontal_move;
+                }
+                Command::Down(depth_move) => aim += depth_move,
+                Command::Up(depth_move) => aim -= depth_move,
+            }
+        }
+
+        horizontal_position * depth
+    }
+}
@@ -0,0 +1,162 @@
+use crate::error::{parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: usize = 3;
+
+    type Input = Vec<Vec<u8>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // every number has to be as wide as the first
+        let mut width = None;
+
+        let lines = parse_lines(input, |line| {
+            let bits = line
+                .chars()
+                .enumerate()
+                .map(|(i, c)| match c {
+                    '0' => Ok(0),
+                    '1' => Ok(1),
+                    other => Err(ParseError::new(
+                        Self::DAY,
+                        line,
+                        i + 1,
+                        format!("expected a bit, found {:?}", other),
+                    )),
+                })
+                .collect::<Result<Vec<u8>, _>>()?;
+
+            match *width.get_or_insert(bits.len()) {
+                0 => Err(ParseError::new(Self::DAY, line, 1, "expected a bit")),
+                width if width != bits.len() => {
+                    let reason = format!("expected {} bits like the first line", width);
+                    Err(ParseError::new(
+                        Self::DAY,
+                        line,
+                        bits.len().min(width) + 1,
+                        reason,
+                    ))
+                }
+                _ => Ok(bits),
+            }
+        })?;
+
+        if lines.is_empty() {
+            return Err(ParseError::empty(Self::DAY));
+        }
+
+        Ok(lines)
+    }
+
+    fn part1(lines_as_bits: &Self::Input) -> Self::Part1 {
+        let len = lines_as_bits[0].len();
+        let mut counts = vec![0; len];
+        let total_length = lines_as_bits.len();
+
+        for line_bits in lines_as_bits {
+            for (i, b) in line_bits.iter().enumerate() {
+                if *b == 1 {
+                    counts[i] += 1;
+                }
+            }
+        }
+
+        let mut gamma: usize = 0;
+        let mut epsilon: usize = 0;
+
+        for (i, count) in counts.iter().rev().enumerate() {
+            if *count > (total_length / 2) {
+                gamma += 2u32.pow(i as u32) as usize
+            } else {
+                epsilon += 2u32.pow(i as u32) as usize
+            }
+        }
+
+        gamma * epsilon
+    }
+
+    fn part2(lines_as_bits: &Self::Input) -> Self::Part2 {
+        let width = lines_as_bits[0].len();
+
+        // identical numbers can't be told apart, so those stop at the last bit
+        let mut bit_i = 0;
+        let mut ox_bits = lines_as_bits.clone();
+
+        while ox_bits.len() > 1 && bit_i < width {
+            let (zeros, ones): (Vec<Vec<u8>>, Vec<Vec<u8>>) = ox_bits
+                .into_iter()
+                .partition(|line_as_bits| line_as_bits[bit_i] == 0);
+
+            ox_bits = if zeros.len() <= ones.len() {
+                ones
+            } else {
+                zeros
+            };
+
+            bit_i += 1;
+        }
+
+        let mut bit_i = 0;
+        let mut co2_bits = lines_as_bits.clone();
+
+        while co2_bits.len() > 1 && bit_i < width {
+            let (zeros, ones): (Vec<Vec<u8>>, Vec<Vec<u8>>) = co2_bits
+                .into_iter()
+                .partition(|line_as_bits| line_as_bits[bit_i] == 0);
+
+            // a bit nobody has isn't the least common, it's just missing
+            co2_bits = if ones.is_empty() || !zeros.is_empty() && zeros.len() <= ones.len() {
+                zeros
+            } else {
+                ones
+            };
+
+            bit_i += 1;
+        }
+
+        let mut ox = 0;
+        let mut co2 = 0;
+
+        for (i, b) in ox_bits[0].iter().rev().enumerate() {
+            if *b == 1 {
+                ox += 2usize.pow(i as u32)
+            }
+        }
+
+        for (i, b) in co2_bits[0].iter().rev().enumerate() {
+            if *b == 1 {
+                co2 += 2usize.pow(i as u32)
+            }
+        }
+
+        ox * co2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_input() {
+        let e = Day3::parse("101\n11111").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+
+        let e = Day3::parse("101\n1").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+
+        assert!(Day3::parse("").is_err());
+        assert!(Day3::parse("\n").is_err());
+    }
+
+    #[test]
+    fn identical_numbers() {
+        let numbers = Day3::parse("101\n101").unwrap();
+
+        assert_eq!(Day3::part2(&numbers), 25);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::error::{parse_field, ParseError};
+use crate::solution::Solution;
+
+pub struct Day4;
+
+#[derive(Clone, Debug)]
+pub struct Bingo {
+    calls: Vec<u8>,
+    boards: Vec<Board>,
+}
+
+impl Solution for Day4 {
+    const DAY: usize = 4;
+
+    type Input = Bingo;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+
+        let calls_line = lines.next().map_or("", |(_, line)| line);
+        let calls = calls_line
+            .split(',')
+            .map(|s| parse_field(Self::DAY, calls_line, s))
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        let board_lines: Vec<(usize, &str)> = lines.filter(|(_, line)| !line.is_empty()).collect();
+
+        let boards = parse_boards(&board_lines)?;
+
+        if boards.is_empty() {
+            let line = input.lines().count() + 1;
+            return Err(ParseError::new(Self::DAY, "", 1, "expected a board").on_line(line));
+        }
+
+        Ok(Bingo { calls, boards })
+    }
+
+    fn part1(bingo: &Self::Input) -> Self::Part1 {
+        let mut boards = bingo.boards.clone();
+
+        let mut winning_call = 0;
+        let mut winning_board = Board::default();
+
+        'calls: for call in bingo.calls.iter().copied() {
+            for board in &mut boards {
+                board.mark_if_appears(call);
+
+                if board.is_win() {
+                    winning_call = call;
+                    winning_board = *board;
+                    break 'calls;
+                }
+            }
+        }
+
+        let unmarked_on_winning_board = winning_board.unmarked_numbers();
+        let unmarked_sum: usize = unmarked_on_winning_board.iter().map(|n| *n as usize).sum();
+
+        unmarked_sum * winning_call as usize
+    }
+
+    fn part2(bingo: &Self::Input) -> Self::Part2 {
+        let mut boards = bingo.boards.clone();
+
+        let mut win_order = 0;
+
+        for call in bingo.calls.iter().copied() {
+            for board in &mut boards {
+                if board.winning_call.is_none() {
+                    board.mark_if_appears(call);
+
+                    if board.is_win() {
+                        win_order += 1;
+                        board.mark_won(win_order, call);
+                    }
+                }
+            }
+        }
+
+        // boards the calls never complete don't win at all; if none win,
+        // the answer is 0 like in part 1
+        let Some((winning_board, winning_call)) = boards.iter().find_map(|board| {
+            board
+                .winning_call
+                .filter(|(order, _)| *order == win_order)
+                .map(|(_, call)| (board, call))
+        }) else {
+            return 0;
+        };
+
+        let unmarked_on_winning_board = winning_board.unmarked_numbers();
+        let unmarked_sum: usize = unmarked_on_winning_board.iter().map(|n| *n as usize).sum();
+
+        unmarked_sum * winning_call as usize
+    }
+}
+
+// Board lines come paired with their index in the whole input, so errors
+// can point at the right line.
+fn parse_boards(board_lines: &[(usize, &str)]) -> Result<Vec<Board>, ParseError> {
+    let mut boards = vec![];
+
+    for chunk in board_lines.chunks_exact(5) {
+        let mut board = Board::default();
+
+        for (board_line, (line_i, line)) in chunk.iter().enumerate() {
+            for (i, s) in line.split_whitespace().enumerate() {
+                if i >= 5 {
+                    let e = ParseError::at(Day4::DAY, line, s, "a board row has only 5 numbers");
+                    return Err(e.on_line(line_i + 1));
+                }
+
+                let n = parse_field(Day4::DAY, line, s).map_err(|e| e.on_line(line_i + 1))?;
+                board.cells_n[board_line * 5 + i] = n;
+            }
+        }
+
+        boards.push(board);
+    }
+
+    Ok(boards)
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Board {
+    cells_n: [u8; 25],
+    cells_set: [bool; 25],
+    winning_call: Option<(usize, u8)>,
+}
+
+impl Board {
+    fn mark_if_appears(&mut self, n: u8) {
+        if let Some(i) = self.cells_n.iter().position(|cell| *cell == n) {
+            self.cells_set[i] = true
+        }
+    }
+
+    fn is_win(&self) -> bool {
+        any_rows_all(self.cells_set) || any_rows_all(transpose(self.cells_set))
+    }
+
+    fn mark_won(&mut self, win_order: usize, winning_call: u8) {
+        self.winning_call = Some((win_order, winning_call))
+    }
+
+    fn unmarked_numbers(&self) -> Vec<u8> {
+        let mut unmarked = vec![];
+
+        for (i, is_set) in self.cells_set.iter().enumerate() {
+            if !is_set {
+                unmarked.push(self.cells_n[i])
+            }
+        }
+
+        unmarked
+    }
+}
+
+fn any_rows_all(array: [bool; 25]) -> bool {
+    array.chunks(5).any(|chunk| chunk.iter().all(|bit| *bit))
+}
+
+fn transpose<T: Copy + Default>(a: [T; 25]) -> [T; 25] {
+    let mut out: [T; 25] = Default::default();
+
+    for y in 0..5 {
+        for x in 0..5 {
+            out[y + x * 5] = a[x + y * 5];
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOARD: &str = "22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19";
+
+    #[test]
+    fn no_boards() {
+        let e = Day4::parse("1,2").unwrap_err();
+        assert_eq!(e.line, 2);
+        assert_eq!(e.reason, "expected a board");
+    }
+
+    #[test]
+    fn boards_that_never_win() {
+        // the first board wins on 24, the second never does
+        let other = BOARD.replace("24", "98");
+        let input = format!("8,2,23,4,24\n\n{}\n\n{}", BOARD, other);
+        let bingo = Day4::parse(&input).unwrap();
+
+        assert_eq!(Day4::part1(&bingo), 239 * 24);
+        assert_eq!(Day4::part2(&bingo), 239 * 24);
+
+        let bingo = Day4::parse(&format!("1,2\n\n{}", BOARD)).unwrap();
+        assert_eq!(Day4::part2(&bingo), 0);
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_field, parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: usize = 5;
+
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let (p1, p2) = line
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::new(Self::DAY, line, 1, "expected `x,y -> x,y`"))?;
+
+            Ok(Line {
+                p1: parse_point(line, p1)?,
+                p2: parse_point(line, p2)?,
+            })
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        let horizontal_and_vertical = lines.iter().filter(|line| line.is_horizontal_or_vertical());
+
+        count_intersections(horizontal_and_vertical)
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        count_intersections(lines.iter())
+    }
+}
+
+fn parse_point(line: &str, s: &str) -> Result<Point, ParseError> {
+    let (x, y) = s
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(Day5::DAY, line, s, "expected `x,y`"))?;
+
+    Ok(Point {
+        x: parse_field(Day5::DAY, line, x)?,
+        y: parse_field(Day5::DAY, line, y)?,
+    })
+}
+
+pub fn count_intersections<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
+    let mut counts = HashMap::new();
+
+    for line in lines {
+        let points = line.all_points_on();
+        for point in points {
+            let entry = counts.entry(point).or_insert(0usize);
+            *entry += 1;
+        }
+    }
+
+    counts.into_values().filter(|v| *v >= 2usize).count()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Line {
+    p1: Point,
+    p2: Point,
+}
+
+impl Line {
+    fn is_horizontal_or_vertical(&self) -> bool {
+        self.p1.x == self.p2.x || self.p1.y == self.p2.y
+    }
+
+    fn all_points_on(&self) -> Box<dyn Iterator<Item = Point> + '_> {
+        if self.p1.x == self.p2.x {
+            let lower = self.p1.y.min(self.p2.y);
+            let upper = self.p1.y.max(self.p2.y);
+
+            Box::new((lower..=upper).map(|n| Point { x: self.p1.x, y: n }))
+        } else if self.p1.y == self.p2.y {
+            let lower = self.p1.x.min(self.p2.x);
+            let upper = self.p1.x.max(self.p2.x);
+
+            Box::new((lower..=upper).map(|n| Point { x: n, y: self.p1.y }))
+        } else {
+            let xrange: Box<dyn Iterator<Item = usize>> = if self.p1.x <= self.p2.x {
+                Box::new(self.p1.x..=self.p2.x)
+            } else {
+                Box::new((self.p2.x..=self.p1.x).rev())
+            };
+
+            let yrange: Box<dyn Iterator<Item = usize>> = if self.p1.y <= self.p2.y {
+                Box::new(self.p1.y..=self.p2.y)
+            } else {
+                Box::new((self.p2.y..=self.p1.y).rev())
+            };
+
+            Box::new(xrange.zip(yrange).map(|(x, y)| Point { x, y }))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonals() {
+        let p1 = Point { x: 0, y: 0 };
+        let p2 = Point { x: 2, y: 2 };
+        let line = Line { p1, p2 };
+        let mut expected = vec![p1, p2, Point { x: 1, y: 1 }];
+        expected.sort_unstable();
+        let mut result = line.all_points_on().collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, expected);
+
+        let p1 = Point { x: 2, y: 2 };
+        let p2 = Point { x: 0, y: 0 };
+        let line = Line { p1, p2 };
+        let mut expected = vec![p1, p2, Point { x: 1, y: 1 }];
+        expected.sort_unstable();
+        let mut result = line.all_points_on().collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, expected);
+
+        let p1 = Point { x: 0, y: 2 };
+        let p2 = Point { x: 2, y: 0 };
+        let line = Line { p1, p2 };
+        let mut expected = vec![p1, p2, Point { x: 1, y: 1 }];
+        expected.sort_unstable();
+        let mut result = line.all_points_on().collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, expected);
+    }
+}
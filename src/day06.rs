@@ -0,0 +1,104 @@
+use crate::error::{parse_field, parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: usize = 6;
+
+    type Input = Vec<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(input, |line| {
+            line.split(',')
+                .map(|s| parse_field::<u8>(Self::DAY, line, s))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part1(fish_days: &Self::Input) -> Self::Part1 {
+        let mut all_fish: Vec<Fish> = fish_days.iter().copied().map(Fish::new).collect();
+
+        let mut todays_fish = vec![];
+
+        for _day in 0..80 {
+            for fish in &mut all_fish {
+                if let Some(new_fish) = fish.tick() {
+                    todays_fish.push(new_fish)
+                }
+            }
+
+            all_fish.extend_from_slice(&todays_fish);
+            todays_fish.clear();
+        }
+
+        all_fish.len()
+    }
+
+    fn part2(fish_days: &Self::Input) -> Self::Part2 {
+        let mut fish = [0usize; 9];
+
+        for n in fish_days {
+            fish[*n as usize] += 1;
+        }
+
+        let mut all_fish = AllFish { fish };
+
+        for _day in 0..256 {
+            all_fish.tick();
+        }
+
+        all_fish.fish.iter().sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Fish {
+    days_remaining: u8,
+}
+
+impl Fish {
+    fn new(days_remaining: u8) -> Self {
+        Self { days_remaining }
+    }
+
+    fn tick(&mut self) -> Option<Fish> {
+        if self.days_remaining == 0 {
+            self.days_remaining = 6;
+            Some(Fish::default())
+        } else {
+            self.days_remaining -= 1;
+            None
+        }
+    }
+}
+
+impl Default for Fish {
+    fn default() -> Self {
+        Self { days_remaining: 8 }
+    }
+}
+
+struct AllFish {
+    fish: [usize; 9],
+}
+
+impl AllFish {
+    fn tick(&mut self) {
+        let zero = self.fish[0];
+
+        self.fish[0] = self.fish[1];
+        self.fish[1] = self.fish[2];
+        self.fish[2] = self.fish[3];
+        self.fish[3] = self.fish[4];
+        self.fish[4] = self.fish[5];
+        self.fish[5] = self.fish[6];
+        self.fish[6] = self.fish[7] + zero;
+        self.fish[7] = self.fish[8];
+        self.fish[8] = zero;
+    }
+}
@@ -0,0 +1,77 @@
+use std::ops::Sub;
+
+use crate::error::{parse_field, parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: usize = 7;
+
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(input, |line| {
+            line.split(',')
+                .map(|s| parse_field::<usize>(Self::DAY, line, s))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+        if lines.is_empty() {
+            return Err(ParseError::empty(Self::DAY));
+        }
+
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part1(positions: &Self::Input) -> Self::Part1 {
+        min_energy(positions, |distance| distance)
+    }
+
+    fn part2(positions: &Self::Input) -> Self::Part2 {
+        min_energy(positions, energy_overhead)
+    }
+}
+
+pub fn min_energy(positions: &[usize], cost: impl Fn(usize) -> usize) -> usize {
+    let min = *positions.iter().min().unwrap();
+    let max = *positions.iter().max().unwrap();
+
+    let mut min_energy = energy_required(min, positions, &cost);
+    for pos in min..max {
+        let energy = energy_required(pos, positions, &cost);
+        if energy < min_energy {
+            min_energy = energy;
+        }
+    }
+
+    min_energy
+}
+
+fn energy_required(
+    chosen_position: usize,
+    positions: &[usize],
+    cost: impl Fn(usize) -> usize,
+) -> usize {
+    let mut energy = 0;
+    for position in positions {
+        energy += cost(abs_difference(*position, chosen_position));
+    }
+
+    energy
+}
+
+fn abs_difference<T: Sub<Output = T> + Ord>(x: T, y: T) -> T {
+    if x < y {
+        y - x
+    } else {
+        x - y
+    }
+}
+
+// https://letstalkscience.ca/educational-resources/backgrounders/gauss-summation
+pub fn energy_overhead(e: usize) -> usize {
+    e * (e + 1) / 2
+}
@@ -0,0 +1,349 @@
+use std::{collections::BTreeSet, fmt::Display, ops::BitXor};
+
+use crate::error::{parse_lines, ParseError};
+use crate::solution::Solution;
+
+// 0: 6 segments
+// 1: 2 segments
+// 2: 5 segments
+// 3: 5 segments
+// 4: 4 segments
+// 5: 5 segments
+// 6: 6 segments
+// 7: 3 segments
+// 8: 7 segments
+// 9: 6 segments
+//
+// all lengths: 2, 3, 4, 5, 6, 7
+// uniqueN: 1, 4, 7, 8
+// nonuniqueN: 0, 2, 3, 5, 6, 9
+// len2: (1)
+// len3: (7)
+// len4: (4)
+// len5: (2|3|5)
+// len6: (0|6|9)
+// len7: (8)
+
+#[derive(Clone, Debug)]
+pub struct Entry {
+    signal_patterns: [SignalPattern; 10],
+    output: [SignalPattern; 4],
+}
+
+#[derive(Clone, Debug)]
+pub enum SignalPattern {
+    Known {
+        digit: Digit,
+        segments: BTreeSet<Segment>,
+    },
+    Unknown {
+        segments: BTreeSet<Segment>,
+    },
+}
+
+impl SignalPattern {
+    fn segments(&self) -> &BTreeSet<Segment> {
+        match self {
+            SignalPattern::Known { segments, .. } => segments,
+            SignalPattern::Unknown { segments, .. } => segments,
+        }
+    }
+
+    fn deduce(&mut self, patterns: &[SignalPattern]) {
+        match self {
+            SignalPattern::Known { .. } => (),
+            SignalPattern::Unknown { segments, .. } => {
+                let one_four_seven_eight = patterns.iter().filter(|pattern| {
+                    matches!(
+                        pattern,
+                        SignalPattern::Known {
+                            digit: Digit::One,
+                            ..
+                        } | SignalPattern::Known {
+                            digit: Digit::Four,
+                            ..
+                        } | SignalPattern::Known {
+                            digit: Digit::Seven,
+                            ..
+                        } | SignalPattern::Known {
+                            digit: Digit::Eight,
+                            ..
+                        }
+                    )
+                });
+
+                let mut xors: [usize; 4] = one_four_seven_eight
+                    .map(|known_pattern| known_pattern.segments().bitxor(segments))
+                    .map(|bxor| bxor.len())
+                    .collect::<Vec<usize>>()
+                    .try_into()
+                    .unwrap();
+
+                xors.sort_unstable();
+
+                let digit = match xors {
+                    [2, 2, 3, 3] => Digit::Three,
+                    [2, 4, 5, 5] => Digit::Two,
+                    [2, 3, 4, 5] => Digit::Five,
+                    [1, 3, 4, 4] => Digit::Zero,
+                    [1, 4, 5, 6] => Digit::Six,
+                    [1, 2, 3, 4] => Digit::Nine,
+                    _ => panic!("{:?} did not match 2, 3, or 5", xors),
+                };
+
+                *self = SignalPattern::Known {
+                    digit,
+                    segments: segments.clone(),
+                };
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Digit {
+    Zero,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Segment {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: usize = 8;
+
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            let (signal_patterns, output) = line.split_once(" | ").ok_or_else(|| {
+                ParseError::new(Self::DAY, line, 1, "expected patterns, ` | `, then output")
+            })?;
+
+            Ok(Entry {
+                signal_patterns: parse_patterns(line, signal_patterns)?,
+                output: parse_patterns(line, output)?,
+            })
+        })
+    }
+
+    fn part1(entries: &Self::Input) -> Self::Part1 {
+        let mut count = 0;
+
+        for entry in entries {
+            for digit in &entry.output {
+                if matches!(digit, SignalPattern::Known { .. }) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    fn part2(entries: &Self::Input) -> Self::Part2 {
+        let mut entries = entries.clone();
+
+        let mut output_total = 0;
+
+        for entry in entries.iter_mut() {
+            let ec = entry.signal_patterns.clone();
+
+            for signal_pattern in entry.signal_patterns.iter_mut() {
+                signal_pattern.deduce(&ec);
+            }
+
+            for output_pattern in entry.output.iter_mut() {
+                *output_pattern = entry
+                    .signal_patterns
+                    .iter()
+                    .find(|sp| sp.segments() == output_pattern.segments())
+                    .cloned()
+                    .unwrap();
+            }
+
+            let mut n = 0;
+
+            for output_digit in entry.output.iter() {
+                match output_digit {
+                    SignalPattern::Known { digit, .. } => n = n * 10 + usize::from(*digit),
+                    _ => unreachable!(),
+                }
+            }
+
+            output_total += n;
+        }
+
+        output_total
+    }
+}
+
+//////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////
+// Everything after this is impls for parsing/displaying/debugging
+//////////////////////////////////////////////////////////////////
+//////////////////////////////////////////////////////////////////
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] | [{}]",
+            self.signal_patterns
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+            self.output
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+}
+
+fn parse_patterns<const N: usize>(line: &str, s: &str) -> Result<[SignalPattern; N], ParseError> {
+    let mut patterns = Vec::with_capacity(N);
+
+    for word in s.split(' ') {
+        if let Some(c) = word.chars().find(|c| !('a'..='g').contains(c)) {
+            let reason = format!("{:?} is not a segment", c);
+            return Err(ParseError::at(Day8::DAY, line, word, reason));
+        }
+
+        patterns.push(SignalPattern::from(word));
+    }
+
+    let found = patterns.len();
+
+    patterns.try_into().map_err(|_| {
+        let reason = format!("expected {} patterns, found {}", N, found);
+        ParseError::at(Day8::DAY, line, s, reason)
+    })
+}
+
+impl From<&str> for SignalPattern {
+    fn from(s: &str) -> Self {
+        let segments = s.chars().map(Segment::from).collect();
+
+        match Digit::try_from(s) {
+            Ok(digit) => SignalPattern::Known { digit, segments },
+            Err(_) => SignalPattern::Unknown { segments },
+        }
+    }
+}
+
+impl Display for SignalPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignalPattern::Known { digit, segments } => write!(
+                f,
+                "{}({})",
+                digit,
+                segments
+                    .iter()
+                    .map(|segment| segment.to_string())
+                    .collect::<Vec<_>>()
+                    .join(""),
+            ),
+            SignalPattern::Unknown { segments } => write!(
+                f,
+                "({})",
+                segments
+                    .iter()
+                    .map(|segment| segment.to_string())
+                    .collect::<Vec<_>>()
+                    .join("")
+            ),
+        }
+    }
+}
+
+impl TryFrom<&str> for Digit {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.len() {
+            2 => Ok(Digit::One),
+            4 => Ok(Digit::Four),
+            3 => Ok(Digit::Seven),
+            7 => Ok(Digit::Eight),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Digit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", usize::from(*self))
+    }
+}
+
+impl From<Digit> for usize {
+    fn from(digit: Digit) -> Self {
+        match digit {
+            Digit::Zero => 0,
+            Digit::One => 1,
+            Digit::Two => 2,
+            Digit::Three => 3,
+            Digit::Four => 4,
+            Digit::Five => 5,
+            Digit::Six => 6,
+            Digit::Seven => 7,
+            Digit::Eight => 8,
+            Digit::Nine => 9,
+        }
+    }
+}
+
+impl From<char> for Segment {
+    fn from(c: char) -> Self {
+        match c {
+            'a' => Segment::A,
+            'b' => Segment::B,
+            'c' => Segment::C,
+            'd' => Segment::D,
+            'e' => Segment::E,
+            'f' => Segment::F,
+            'g' => Segment::G,
+            _ => panic!(),
+        }
+    }
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out = match self {
+            Segment::A => "a",
+            Segment::B => "b",
+            Segment::C => "c",
+            Segment::D => "d",
+            Segment::E => "e",
+            Segment::F => "f",
+            Segment::G => "g",
+        };
+
+        write!(f, "{}", out)
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct HeightMap(Grid<u8>);
+
+impl HeightMap {
+    fn adjacent_values(&self, i: usize) -> impl Iterator<Item = u8> + '_ {
+        self.0.neighbours4(i).map(|adjacent_i| self.0[adjacent_i])
+    }
+
+    fn is_lowpoint(&self, i: usize) -> bool {
+        let value = self.0[i];
+
+        self.adjacent_values(i)
+            .all(|adjacent_value| value < adjacent_value)
+    }
+
+    pub fn lowpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.0.len()).filter(|i| self.is_lowpoint(*i))
+    }
+
+    // Flood fill out from a low point, stopping at 9s and the edges.
+    pub fn basin_size(&self, lowpoint: usize, in_basin: &mut [bool]) -> usize {
+        let mut queue = VecDeque::from([lowpoint]);
+        let mut size = 0;
+
+        in_basin[lowpoint] = true;
+
+        while let Some(this_i) = queue.pop_front() {
+            size += 1;
+
+            for adjacent in self.0.neighbours4(this_i) {
+                if !in_basin[adjacent] && self.0[adjacent] < 9 {
+                    in_basin[adjacent] = true;
+                    queue.push_back(adjacent);
+                }
+            }
+        }
+
+        size
+    }
+}
+
+const fn risk_level(value: u8) -> u8 {
+    1 + value
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: usize = 9;
+
+    type Input = HeightMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let heights = Grid::from_digits(Self::DAY, input)?;
+
+        if heights.is_empty() {
+            return Err(ParseError::empty(Self::DAY));
+        }
+
+        Ok(HeightMap(heights))
+    }
+
+    fn part1(heightmap: &Self::Input) -> Self::Part1 {
+        heightmap
+            .lowpoints()
+            .map(|i| risk_level(heightmap.0[i]) as usize)
+            .sum()
+    }
+
+    fn part2(heightmap: &Self::Input) -> Self::Part2 {
+        let mut in_basin = vec![false; heightmap.0.len()];
+
+        // two basins can be the same size, so this can't be a set
+        let mut basin_sizes: Vec<usize> = heightmap
+            .lowpoints()
+            .map(|i| heightmap.basin_size(i, &mut in_basin))
+            .collect();
+
+        basin_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        basin_sizes.iter().take(3).product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn sample() {
+        let heightmap = Day9::parse(SAMPLE).unwrap();
+
+        assert_eq!(Day9::part1(&heightmap), 15);
+        assert_eq!(Day9::part2(&heightmap), 1134);
+    }
+
+    #[test]
+    fn same_sized_basins() {
+        // three basins of 2 cells each
+        let heightmap = Day9::parse("01901\n99999\n01999").unwrap();
+
+        assert_eq!(heightmap.lowpoints().count(), 3);
+        assert_eq!(Day9::part2(&heightmap), 8);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Day9::parse("").is_err());
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::Display;
+
+use crate::error::{parse_lines, ParseError};
+use crate::solution::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Token {
+    OpenParen,
+    CloseParen,
+    OpenSquare,
+    CloseSquare,
+    OpenCurly,
+    CloseCurly,
+    OpenAngle,
+    CloseAngle,
+}
+
+impl Token {
+    fn close(&self) -> Token {
+        match self {
+            Token::OpenParen => Token::CloseParen,
+            Token::OpenSquare => Token::CloseSquare,
+            Token::OpenCurly => Token::CloseCurly,
+            Token::OpenAngle => Token::CloseAngle,
+            _ => panic!("Token {:?} is not closable", self),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LineStatus {
+    Valid,
+    Corrupt { expected: Token, illegal: Token },
+    Incomplete { expected: Vec<Token> },
+}
+
+impl LineStatus {
+    pub fn corrupt_score(&self) -> usize {
+        match self {
+            LineStatus::Corrupt { illegal, .. } => match illegal {
+                Token::CloseParen => 3,
+                Token::CloseSquare => 57,
+                Token::CloseCurly => 1197,
+                Token::CloseAngle => 25137,
+                token => panic!("Encountered invalid token: {:?}", token),
+            },
+            _ => 0,
+        }
+    }
+
+    pub fn incomplete_score(&self) -> usize {
+        match self {
+            LineStatus::Incomplete { expected } => {
+                let mut score = 0;
+
+                for expected_token in expected {
+                    score *= 5;
+
+                    match expected_token {
+                        Token::CloseParen => score += 1,
+                        Token::CloseSquare => score += 2,
+                        Token::CloseCurly => score += 3,
+                        Token::CloseAngle => score += 4,
+                        token => panic!("Encountered invalid token: {:?}", token),
+                    }
+                }
+
+                score
+            }
+            _ => 0,
+        }
+    }
+}
+
+pub fn lex(line: &str) -> Result<Vec<Token>, ParseError> {
+    let s = line.trim();
+    s.char_indices()
+        .map(|(i, c)| match c {
+            '(' => Ok(Token::OpenParen),
+            ')' => Ok(Token::CloseParen),
+            '[' => Ok(Token::OpenSquare),
+            ']' => Ok(Token::CloseSquare),
+            '{' => Ok(Token::OpenCurly),
+            '}' => Ok(Token::CloseCurly),
+            '<' => Ok(Token::OpenAngle),
+            '>' => Ok(Token::CloseAngle),
+            _ => Err(ParseError::at(
+                Day10::DAY,
+                line,
+                &s[i..],
+                format!("{:?} is not a bracket", c),
+            )),
+        })
+        .collect()
+}
+
+pub fn parse(tokens: &[Token]) -> LineStatus {
+    let mut counter = 0;
+    let mut expected = vec![];
+
+    for token in tokens {
+        match token {
+            Token::OpenParen | Token::OpenSquare | Token::OpenCurly | Token::OpenAngle => {
+                expected.push(token.close());
+                counter += 1;
+            }
+            Token::CloseParen | Token::CloseSquare | Token::CloseCurly | Token::CloseAngle => {
+                if let Some(expected_token) = expected.pop() {
+                    if expected_token != *token {
+                        return LineStatus::Corrupt {
+                            expected: expected_token,
+                            illegal: *token,
+                        };
+                    }
+                }
+                counter -= 1;
+            }
+        }
+    }
+
+    if counter != 0 {
+        expected.reverse();
+
+        return LineStatus::Incomplete { expected };
+    }
+
+    LineStatus::Valid
+}
+
+/// The middle score of the incomplete lines, or `None` if every line was
+/// corrupt or complete, which leaves nothing to take the middle of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MiddleScore(pub Option<usize>);
+
+impl Display for MiddleScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(score) => write!(f, "{}", score),
+            None => write!(f, "no incomplete lines"),
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    type Input = Vec<Vec<Token>>;
+    type Part1 = usize;
+    type Part2 = MiddleScore;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(input, lex)?;
+
+        if lines.is_empty() {
+            return Err(ParseError::empty(Self::DAY));
+        }
+
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Part1 {
+        let mut corrupt_score = 0;
+
+        for lexed in lines {
+            let line_status = parse(lexed);
+            corrupt_score += line_status.corrupt_score();
+        }
+
+        corrupt_score
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Part2 {
+        let mut scores = vec![];
+
+        for lexed in lines {
+            let line_status = parse(lexed);
+            if let LineStatus::Incomplete { .. } = line_status {
+                let score = line_status.incomplete_score();
+                scores.push(score);
+            };
+        }
+
+        scores.sort_unstable();
+        let mid = scores.len() / 2;
+
+        MiddleScore(scores.get(mid).copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_valid() {
+        let input = "(())";
+        let lexed = lex(input).unwrap();
+        let parsed = parse(&lexed);
+        assert_eq!(parsed, LineStatus::Valid)
+    }
+
+    #[test]
+    fn simple_corrupt() {
+        let input = "(()]";
+        let lexed = lex(input).unwrap();
+        let parsed = parse(&lexed);
+        assert_eq!(
+            parsed,
+            LineStatus::Corrupt {
+                expected: Token::CloseParen,
+                illegal: Token::CloseSquare
+            }
+        )
+    }
+
+    #[test]
+    fn more_complex_corrupt() {
+        let input = "(()[]{}<><<>>}";
+        let lexed = lex(input).unwrap();
+        let parsed = parse(&lexed);
+        assert_eq!(
+            parsed,
+            LineStatus::Corrupt {
+                expected: Token::CloseParen,
+                illegal: Token::CloseCurly
+            }
+        )
+    }
+
+    #[test]
+    fn simple_incomplete() {
+        let input = "(()";
+        let lexed = lex(input).unwrap();
+        let parsed = parse(&lexed);
+        assert_eq!(
+            parsed,
+            LineStatus::Incomplete {
+                expected: vec![Token::CloseParen],
+            }
+        )
+    }
+
+    #[test]
+    fn more_complex_incomplete() {
+        let input = "(()[<(";
+        let lexed = lex(input).unwrap();
+        let parsed = parse(&lexed);
+        assert_eq!(
+            parsed,
+            LineStatus::Incomplete {
+                expected: vec![
+                    Token::CloseParen,
+                    Token::CloseAngle,
+                    Token::CloseSquare,
+                    Token::CloseParen
+                ],
+            }
+        )
+    }
+
+    #[test]
+    fn bad_character() {
+        let e = Day10::parse("()\n(<x>)").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+    }
+
+    #[test]
+    fn no_incomplete_lines() {
+        let lines = Day10::parse("{([(<{}[<>[]}>{[]{[(<()>\n()").unwrap();
+
+        assert_eq!(Day10::part1(&lines), 1197);
+        assert_eq!(Day10::part2(&lines), MiddleScore(None));
+
+        let lines = Day10::parse("[({(<(())[]>[[{[]{<()<>>").unwrap();
+        assert_eq!(Day10::part2(&lines), MiddleScore(Some(288957)));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Day10::parse("").is_err());
+    }
+}
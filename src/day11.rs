@@ -0,0 +1,184 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+#[derive(Clone)]
+pub struct EnergyMap {
+    map: Grid<u8>,
+}
+
+impl EnergyMap {
+    pub fn tick(&mut self) -> usize {
+        // First, the energy level of each octopus increases by 1.
+        for energy_level in self.map.iter_mut() {
+            *energy_level += 1;
+        }
+
+        // Then, any octopus with an energy level greater than 9 flashes.
+        // This increases the energy level of all adjacent octopuses by 1,
+        // including octopuses that are diagonally adjacent.
+        // If this causes an octopus to have an energy level greater than 9,
+        // it also flashes.
+        // This process continues as long as new octopuses keep having their
+        // energy level increased beyond 9.
+        // (An octopus can only flash at most once per step.)
+        let mut most_recent_flashed: BTreeSet<_> = self
+            .map
+            .iter()
+            .enumerate()
+            .filter(|(_i, energy)| **energy > 9)
+            .map(|(i, _energy)| i)
+            .collect();
+
+        let mut all_flashed: BTreeSet<usize> = most_recent_flashed.clone();
+
+        loop {
+            let all_adjacents: Vec<usize> = most_recent_flashed
+                .iter()
+                .flat_map(|i| self.map.neighbours8(*i))
+                .filter(|i| !all_flashed.contains(i))
+                .collect();
+
+            for adjacent in &all_adjacents {
+                self.map[*adjacent] += 1;
+            }
+
+            let unique_adjacents: BTreeSet<_> = all_adjacents.into_iter().collect();
+
+            let adjacent_flashes: Vec<_> = unique_adjacents
+                .into_iter()
+                .filter(|i| self.map[*i] > 9)
+                .collect();
+
+            all_flashed.extend(adjacent_flashes.iter().copied());
+
+            if adjacent_flashes.is_empty() {
+                break;
+            } else {
+                most_recent_flashed = adjacent_flashes.iter().copied().collect();
+            }
+        }
+
+        // Finally, any octopus that flashed during this step has its energy level set to 0,
+        // as it used all of its energy to flash.
+        for i in &all_flashed {
+            self.map[*i] = 0;
+        }
+
+        all_flashed.len()
+    }
+
+    pub fn is_synchronized(&self) -> bool {
+        let first = self.map[0];
+        self.map.iter().all(|energy| *energy == first)
+    }
+}
+
+impl Display for EnergyMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in self.map.rows() {
+            writeln!(f, "{:?}", chunk)?
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: usize = 11;
+
+    type Input = EnergyMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::from_digits(Self::DAY, input)?;
+
+        if map.is_empty() {
+            return Err(ParseError::empty(Self::DAY));
+        }
+
+        Ok(EnergyMap { map })
+    }
+
+    fn part1(energy_map: &Self::Input) -> Self::Part1 {
+        let mut energy_map = energy_map.clone();
+
+        let mut total_flashes = 0;
+
+        for _tick in 0..100 {
+            total_flashes += energy_map.tick();
+        }
+
+        total_flashes
+    }
+
+    fn part2(energy_map: &Self::Input) -> Self::Part2 {
+        let mut energy_map = energy_map.clone();
+
+        let mut tick = 0;
+
+        loop {
+            tick += 1;
+
+            energy_map.tick();
+
+            if energy_map.is_synchronized() {
+                break;
+            }
+        }
+
+        tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let input = [
+            5, 4, 8, 3, 1, 4, 3, 2, 2, 3, 2, 7, 4, 5, 8, 5, 4, 7, 1, 1, 5, 2, 6, 4, 5, 5, 6, 1, 7,
+            3, 6, 1, 4, 1, 3, 3, 6, 1, 4, 6, 6, 3, 5, 7, 3, 8, 5, 4, 7, 8, 4, 1, 6, 7, 5, 2, 4, 6,
+            4, 5, 2, 1, 7, 6, 8, 4, 1, 7, 2, 1, 6, 8, 8, 2, 8, 8, 1, 1, 3, 4, 4, 8, 4, 6, 8, 4, 8,
+            5, 5, 4, 5, 2, 8, 3, 7, 5, 1, 5, 2, 6,
+        ];
+
+        let after_1_tick = [
+            6, 5, 9, 4, 2, 5, 4, 3, 3, 4, 3, 8, 5, 6, 9, 6, 5, 8, 2, 2, 6, 3, 7, 5, 6, 6, 7, 2, 8,
+            4, 7, 2, 5, 2, 4, 4, 7, 2, 5, 7, 7, 4, 6, 8, 4, 9, 6, 5, 8, 9, 5, 2, 7, 8, 6, 3, 5, 7,
+            5, 6, 3, 2, 8, 7, 9, 5, 2, 8, 3, 2, 7, 9, 9, 3, 9, 9, 2, 2, 4, 5, 5, 9, 5, 7, 9, 5, 9,
+            6, 6, 5, 6, 3, 9, 4, 8, 6, 2, 6, 3, 7,
+        ];
+
+        let after_2_tick = [
+            8, 8, 0, 7, 4, 7, 6, 5, 5, 5, 5, 0, 8, 9, 0, 8, 7, 0, 5, 4, 8, 5, 9, 7, 8, 8, 9, 6, 0,
+            8, 8, 4, 8, 5, 7, 6, 9, 6, 0, 0, 8, 7, 0, 0, 9, 0, 8, 8, 0, 0, 6, 6, 0, 0, 0, 8, 8, 9,
+            8, 9, 6, 8, 0, 0, 0, 0, 5, 9, 4, 3, 0, 0, 0, 0, 0, 0, 7, 4, 5, 6, 9, 0, 0, 0, 0, 0, 0,
+            8, 7, 6, 8, 7, 0, 0, 0, 0, 6, 8, 4, 8,
+        ];
+
+        let mut energy_map = EnergyMap {
+            map: Grid::new(10, 10, input.to_vec()),
+        };
+
+        energy_map.tick();
+
+        assert_eq!(energy_map.map.cells(), after_1_tick);
+
+        energy_map.tick();
+
+        assert_eq!(energy_map.map.cells(), after_2_tick);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Day11::parse("").is_err());
+    }
+}
@@ -0,0 +1,666 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::error::{parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub type Edge<'a> = [Cave<'a>; 2];
+
+pub fn lex(line: &str) -> Result<Edge<'_>, ParseError> {
+    let (from, to) = line
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(Day12::DAY, line, 1, "expected `cave-cave`"))?;
+
+    Ok([lex_cave(line, from)?, lex_cave(line, to)?])
+}
+
+fn lex_cave<'a>(line: &str, name: &'a str) -> Result<Cave<'a>, ParseError> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase()) {
+        Ok(Cave::Small { name })
+    } else if !name.is_empty() && name.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(Cave::Large { name })
+    } else {
+        let reason = format!(
+            "`{}` is not an all-lowercase or all-uppercase cave name",
+            name
+        );
+        Err(ParseError::at(Day12::DAY, line, name, reason))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Cave<'a> {
+    Large { name: &'a str },
+    Small { name: &'a str },
+}
+
+impl<'a> Cave<'a> {
+    pub fn name(&self) -> &'a str {
+        match self {
+            Cave::Large { name } | Cave::Small { name } => name,
+        }
+    }
+}
+
+/// A set of caves by id, as big as the network it's for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CaveSet {
+    words: Vec<u64>,
+}
+
+impl CaveSet {
+    pub fn new(caves: usize) -> Self {
+        Self {
+            words: vec![0; caves.div_ceil(64)],
+        }
+    }
+
+    pub fn contains(&self, cave: usize) -> bool {
+        self.words[cave / 64] & 1 << (cave % 64) != 0
+    }
+
+    pub fn insert(&mut self, cave: usize) {
+        self.words[cave / 64] |= 1 << (cave % 64);
+    }
+
+    pub fn remove(&mut self, cave: usize) {
+        self.words[cave / 64] &= !(1 << (cave % 64));
+    }
+
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+}
+
+/// The caves, with their names swapped for dense ids so walking it doesn't
+/// care what they're called.
+#[derive(Clone, Debug)]
+pub struct CaveNetwork {
+    names: Vec<String>,
+    small: Vec<bool>,
+    ids: HashMap<String, usize>,
+    adjacencies: Vec<Vec<usize>>,
+    edges: Vec<[usize; 2]>,
+}
+
+impl Default for CaveNetwork {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CaveNetwork {
+    /// `start` and `end` are always there, even if nothing leads to them.
+    pub const START: usize = 0;
+    pub const END: usize = 1;
+
+    pub fn new() -> Self {
+        let mut cave_network = CaveNetwork {
+            names: vec![],
+            small: vec![],
+            ids: HashMap::new(),
+            adjacencies: vec![],
+            edges: vec![],
+        };
+
+        cave_network.intern(Cave::Small { name: "start" });
+        cave_network.intern(Cave::Small { name: "end" });
+
+        cave_network
+    }
+
+    /// Joins two caves. Two large caves can't be joined, since a path could
+    /// go back and forth between them forever; the error for that points at
+    /// the edge written as `from-to`, the way it is in the input.
+    pub fn add_edge(&mut self, edge: Edge<'_>) -> Result<(), ParseError> {
+        if let [Cave::Large { name: from }, Cave::Large { name: to }] = edge {
+            let text = format!("{}-{}", from, to);
+            let reason = format!("large caves `{}` and `{}` can't be joined", from, to);
+            return Err(ParseError::new(Day12::DAY, &text, from.len() + 2, reason));
+        }
+
+        let e0 = self.intern(edge[0]);
+        let e1 = self.intern(edge[1]);
+
+        self.adjacencies[e0].push(e1);
+        self.adjacencies[e1].push(e0);
+        self.edges.push([e0, e1]);
+
+        Ok(())
+    }
+
+    fn intern(&mut self, cave: Cave<'_>) -> usize {
+        if let Some(id) = self.ids.get(cave.name()) {
+            return *id;
+        }
+
+        self.names.push(cave.name().to_string());
+        self.small.push(matches!(cave, Cave::Small { .. }));
+        self.ids
+            .insert(cave.name().to_string(), self.names.len() - 1);
+        self.adjacencies.push(vec![]);
+
+        self.names.len() - 1
+    }
+
+    /// How many caves there are, which is one more than the largest id.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, cave: usize) -> &str {
+        &self.names[cave]
+    }
+
+    pub fn is_small(&self, cave: usize) -> bool {
+        self.small[cave]
+    }
+
+    pub fn neighbours(&self, cave: usize) -> &[usize] {
+        &self.adjacencies[cave]
+    }
+
+    /// How many paths there are from `start` to `end` that keep to `policy`.
+    /// `start` is never gone back into.
+    pub fn count_paths(&self, policy: &Policy) -> usize {
+        let walker = Walker {
+            network: self,
+            revisits: policy.revisits,
+            forbidden: policy.forbidden_set(self),
+        };
+
+        let mut visits = Visits::new(self.len(), policy.revisits);
+
+        // The ways on from a cave only depend on how often each small cave
+        // has been visited and whether the second visit is used up, so each
+        // of those is only counted once. Keeping them by cave means looking
+        // one up doesn't need a key of its own.
+        let mut counts = vec![HashMap::new(); self.len()];
+
+        walker.count_from(Self::START, &mut visits, &mut counts)
+    }
+
+    /// The network in Graphviz's DOT language, for rendering with `dot`.
+    pub fn dot(&self) -> Dot<'_> {
+        Dot { network: self }
+    }
+
+    /// Every path from `start` to `end` that keeps to `policy`, one at a time
+    /// as the names of the caves along it. They come out sorted, the same as
+    /// the puzzle lists them.
+    pub fn paths(&self, policy: &Policy) -> Paths<'_> {
+        // going through the neighbours in order of name finds the paths in
+        // order too, since no path can carry on past `end`
+        let sorted = (0..self.len())
+            .map(|cave| {
+                let mut neighbours = self.neighbours(cave).to_vec();
+                neighbours.sort_unstable_by_key(|cave| self.name(*cave));
+                neighbours
+            })
+            .collect();
+
+        Paths {
+            walker: Walker {
+                network: self,
+                revisits: policy.revisits,
+                forbidden: policy.forbidden_set(self),
+            },
+            sorted,
+            route: vec![Self::START],
+            next: vec![0],
+            twice_before: vec![false],
+            visits: Visits::new(self.len(), policy.revisits),
+        }
+    }
+}
+
+/// The iterator from `CaveNetwork::paths`, which walks the caves depth first
+/// and stops at each path it finishes.
+pub struct Paths<'a> {
+    walker: Walker<'a>,
+    sorted: Vec<Vec<usize>>,
+    // the path so far, with the index of the next neighbour to try from each
+    // cave on it and whether the second visit was used before getting there
+    route: Vec<usize>,
+    next: Vec<usize>,
+    twice_before: Vec<bool>,
+    visits: Visits,
+}
+
+impl Paths<'_> {
+    fn backtrack(&mut self) {
+        let cave = self.route.pop().unwrap();
+        self.next.pop();
+        let twice_before = self.twice_before.pop().unwrap();
+
+        // `start` is where every path begins, it's never gone into
+        if cave != CaveNetwork::START {
+            self.walker.leave(cave, twice_before, &mut self.visits);
+        }
+    }
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&cave) = self.route.last() {
+            let i = *self.next.last().unwrap();
+
+            if cave == CaveNetwork::END || i == self.sorted[cave].len() {
+                self.backtrack();
+                continue;
+            }
+
+            *self.next.last_mut().unwrap() += 1;
+
+            let out_node = self.sorted[cave][i];
+
+            let Some(twice) = self.walker.enter(out_node, &self.visits) else {
+                continue;
+            };
+
+            self.twice_before.push(self.visits.twice);
+            self.walker.visit(out_node, twice, &mut self.visits);
+            self.route.push(out_node);
+            self.next.push(0);
+
+            if out_node == CaveNetwork::END {
+                let network = self.walker.network;
+                return Some(self.route.iter().map(|cave| network.name(*cave)).collect());
+            }
+        }
+
+        None
+    }
+}
+
+/// A cave network written out as a DOT graph. Small caves are circles and
+/// large caves are grey boxes, with `start` and `end` coloured in.
+pub struct Dot<'a> {
+    network: &'a CaveNetwork,
+}
+
+impl Display for Dot<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let network = self.network;
+
+        writeln!(f, "graph caves {{")?;
+
+        for cave in 0..network.len() {
+            let style = match cave {
+                CaveNetwork::START => "shape=doublecircle, style=filled, fillcolor=palegreen",
+                CaveNetwork::END => "shape=doublecircle, style=filled, fillcolor=lightpink",
+                _ if network.is_small(cave) => "shape=circle",
+                _ => "shape=box, style=filled, fillcolor=lightgrey",
+            };
+
+            writeln!(f, "    \"{}\" [{}];", network.name(cave), style)?;
+        }
+
+        for [from, to] in &network.edges {
+            writeln!(
+                f,
+                "    \"{}\" -- \"{}\";",
+                network.name(*from),
+                network.name(*to)
+            )?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+/// How often small caves can be visited. Large caves can always be.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Revisits {
+    /// Every small cave at most once, as in part 1.
+    Never,
+    /// One small cave twice and the rest at most once, as in part 2.
+    OneTwice,
+    /// Every small cave up to this many times.
+    UpTo(u8),
+}
+
+/// The rules for which caves a path can go into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Policy {
+    pub revisits: Revisits,
+    /// Caves a path can't go into at all. Names not in the network are
+    /// ignored.
+    pub forbidden: Vec<String>,
+}
+
+impl Policy {
+    pub fn new(revisits: Revisits) -> Self {
+        Self {
+            revisits,
+            forbidden: vec![],
+        }
+    }
+
+    pub fn forbid(mut self, name: &str) -> Self {
+        self.forbidden.push(name.to_string());
+        self
+    }
+
+    fn forbidden_set(&self, network: &CaveNetwork) -> CaveSet {
+        let mut forbidden = CaveSet::new(network.len());
+
+        for cave in self.forbidden.iter().filter_map(|name| network.id(name)) {
+            forbidden.insert(cave);
+        }
+
+        forbidden
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Visits {
+    // small caves gone into at least once
+    seen: CaveSet,
+    // whether the one second visit is used up
+    twice: bool,
+    // how often each small cave has been gone into, which only
+    // `Revisits::UpTo` needs; it's empty otherwise
+    counts: Vec<u8>,
+}
+
+impl Visits {
+    fn new(caves: usize, revisits: Revisits) -> Self {
+        let counts = match revisits {
+            Revisits::UpTo(_) => vec![0; caves],
+            Revisits::Never | Revisits::OneTwice => vec![],
+        };
+
+        Self {
+            seen: CaveSet::new(caves),
+            twice: false,
+            counts,
+        }
+    }
+}
+
+struct Walker<'a> {
+    network: &'a CaveNetwork,
+    revisits: Revisits,
+    forbidden: CaveSet,
+}
+
+impl Walker<'_> {
+    // Whether `cave` can be gone into next, and if so whether that uses up
+    // the second visit.
+    fn enter(&self, cave: usize, visits: &Visits) -> Option<bool> {
+        if cave == CaveNetwork::START || self.forbidden.contains(cave) {
+            return None;
+        }
+
+        if !self.network.is_small(cave) || cave == CaveNetwork::END {
+            return Some(visits.twice);
+        }
+
+        let seen = visits.seen.contains(cave);
+
+        match self.revisits {
+            Revisits::Never => (!seen).then_some(visits.twice),
+            Revisits::OneTwice if !seen => Some(visits.twice),
+            Revisits::OneTwice => (!visits.twice).then_some(true),
+            Revisits::UpTo(n) => (visits.counts[cave] < n).then_some(visits.twice),
+        }
+    }
+
+    // Goes into `cave`, which `enter` allowed along with `twice`.
+    fn visit(&self, cave: usize, twice: bool, visits: &mut Visits) {
+        if self.network.is_small(cave) {
+            match self.revisits {
+                Revisits::UpTo(_) => visits.counts[cave] += 1,
+                Revisits::Never | Revisits::OneTwice => visits.seen.insert(cave),
+            }
+        }
+
+        visits.twice = twice;
+    }
+
+    // Undoes `visit`, given whether the second visit was used up before it.
+    fn leave(&self, cave: usize, twice_before: bool, visits: &mut Visits) {
+        if self.network.is_small(cave) {
+            match self.revisits {
+                Revisits::UpTo(_) => visits.counts[cave] -= 1,
+                // the second visit found it already seen, so it stays seen
+                Revisits::Never | Revisits::OneTwice if visits.twice && !twice_before => {}
+                Revisits::Never | Revisits::OneTwice => visits.seen.remove(cave),
+            }
+        }
+
+        visits.twice = twice_before;
+    }
+
+    fn count_from(
+        &self,
+        cave: usize,
+        visits: &mut Visits,
+        counts: &mut [HashMap<Visits, usize>],
+    ) -> usize {
+        if cave == CaveNetwork::END {
+            return 1;
+        }
+
+        if let Some(count) = counts[cave].get(visits) {
+            return *count;
+        }
+
+        let mut count = 0;
+
+        for &out_node in self.network.neighbours(cave) {
+            let Some(twice) = self.enter(out_node, visits) else {
+                continue;
+            };
+
+            let twice_before = visits.twice;
+
+            self.visit(out_node, twice, visits);
+            count += self.count_from(out_node, visits, counts);
+            self.leave(out_node, twice_before, visits);
+        }
+
+        counts[cave].insert(visits.clone(), count);
+
+        count
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: usize = 12;
+
+    type Input = CaveNetwork;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut cave_network = CaveNetwork::new();
+
+        parse_lines(input, |line| cave_network.add_edge(lex(line)?))?;
+
+        Ok(cave_network)
+    }
+
+    fn part1(cave_network: &Self::Input) -> Self::Part1 {
+        cave_network.count_paths(&Policy::new(Revisits::Never))
+    }
+
+    fn part2(cave_network: &Self::Input) -> Self::Part2 {
+        cave_network.count_paths(&Policy::new(Revisits::OneTwice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the smallest example from the puzzle
+    const SMALL: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    #[test]
+    fn it_works() {
+        let graph = Day12::parse(include_str!("../inputs/12.txt")).unwrap();
+
+        assert_eq!(graph.count_paths(&Policy::new(Revisits::Never)), 3738);
+        assert_eq!(graph.count_paths(&Policy::new(Revisits::OneTwice)), 120506);
+        assert_eq!(
+            graph.paths(&Policy::new(Revisits::OneTwice)).count(),
+            120506
+        )
+    }
+
+    #[test]
+    fn other_names() {
+        // the larger example from the puzzle
+        let input = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+        let graph = Day12::parse(input).unwrap();
+
+        assert_eq!(Day12::part1(&graph), 19);
+        assert_eq!(Day12::part2(&graph), 103);
+        assert_eq!(graph.name(graph.id("kj").unwrap()), "kj");
+        assert!(!graph.is_small(graph.id("HN").unwrap()));
+    }
+
+    #[test]
+    fn small_example() {
+        let graph = Day12::parse(SMALL).unwrap();
+
+        assert_eq!(graph.count_paths(&Policy::new(Revisits::Never)), 10);
+        assert_eq!(graph.count_paths(&Policy::new(Revisits::OneTwice)), 36);
+    }
+
+    #[test]
+    fn policies() {
+        let graph = Day12::parse(SMALL).unwrap();
+
+        // visiting up to once is the same as never going back
+        assert_eq!(graph.count_paths(&Policy::new(Revisits::UpTo(1))), 10);
+        assert!(graph.count_paths(&Policy::new(Revisits::UpTo(2))) > 36);
+
+        // without b, it's start-A-end with or without a trip to c
+        let no_b = Policy::new(Revisits::Never).forbid("b");
+        assert_eq!(graph.count_paths(&no_b), 2);
+
+        let nowhere = Policy::new(Revisits::OneTwice).forbid("end");
+        assert_eq!(graph.count_paths(&nowhere), 0);
+
+        let unknown = Policy::new(Revisits::Never).forbid("zz");
+        assert_eq!(graph.count_paths(&unknown), 10);
+    }
+
+    #[test]
+    fn listed_paths() {
+        let graph = Day12::parse(SMALL).unwrap();
+
+        let paths: Vec<_> = graph
+            .paths(&Policy::new(Revisits::Never))
+            .map(|path| path.join(","))
+            .collect();
+
+        // in the puzzle's order
+        assert_eq!(
+            paths,
+            [
+                "start,A,b,A,c,A,end",
+                "start,A,b,A,end",
+                "start,A,b,end",
+                "start,A,c,A,b,A,end",
+                "start,A,c,A,b,end",
+                "start,A,c,A,end",
+                "start,A,end",
+                "start,b,A,c,A,end",
+                "start,b,A,end",
+                "start,b,end",
+            ]
+        );
+
+        let policy = Policy::new(Revisits::OneTwice);
+        let twice: Vec<_> = graph.paths(&policy).collect();
+        assert_eq!(twice.len(), graph.count_paths(&policy));
+        assert!(twice.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            twice[0],
+            ["start", "A", "b", "A", "b", "A", "c", "A", "end"]
+        );
+
+        let policy = Policy::new(Revisits::UpTo(2));
+        assert_eq!(graph.paths(&policy).count(), graph.count_paths(&policy));
+    }
+
+    #[test]
+    fn dot() {
+        let graph = Day12::parse("start-A\nA-b\nb-end").unwrap();
+
+        assert_eq!(
+            graph.dot().to_string(),
+            r#"graph caves {
+    "start" [shape=doublecircle, style=filled, fillcolor=palegreen];
+    "end" [shape=doublecircle, style=filled, fillcolor=lightpink];
+    "A" [shape=box, style=filled, fillcolor=lightgrey];
+    "b" [shape=circle];
+    "start" -- "A";
+    "A" -- "b";
+    "b" -- "end";
+}
+"#
+        );
+    }
+
+    #[test]
+    fn cave_set() {
+        let mut set = CaveSet::new(130);
+
+        set.insert(3);
+        set.insert(129);
+        assert!(set.contains(129) && set.contains(3) && !set.contains(64));
+
+        set.remove(3);
+        assert!(!set.contains(3));
+        assert_eq!(set.words().len(), 3);
+    }
+
+    #[test]
+    fn bad_edges() {
+        let e = Day12::parse("start-A\nA-bC").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+
+        assert!(Day12::parse("start").is_err());
+
+        let e = Day12::parse("start-A\nA-B\nB-end").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "A-B"));
+
+        let mut cave_network = CaveNetwork::new();
+        let edge = [Cave::Large { name: "A" }, Cave::Large { name: "B" }];
+        assert!(cave_network.add_edge(edge).is_err());
+        assert_eq!(cave_network.len(), 2);
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::Display;
+
+use crate::error::{parse_field, ParseError};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+#[derive(Clone)]
+struct Paper {
+    positions: Grid<bool>,
+    real_width: usize,
+    real_height: usize,
+}
+
+impl Display for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.positions.rows().take(self.real_height) {
+            for dot in row.iter().take(self.real_width) {
+                if *dot {
+                    write!(f, "# ")?;
+                } else {
+                    write!(f, "  ")?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Paper {
+    fn fold(&mut self, fold: &Fold) {
+        match fold {
+            Fold::X(amount) => self.fold_x(*amount),
+            Fold::Y(amount) => self.fold_y(*amount),
+        }
+    }
+
+    fn fold_x(&mut self, fold: usize) {
+        let mut new_dots = vec![];
+
+        let positions = &mut self.positions;
+
+        for i in 0..positions.len() {
+            if positions[i] {
+                let (x, y) = positions.i_to_xy(i);
+                if x > fold {
+                    let diff = x - fold;
+                    let new_x = fold - diff;
+                    let new_i = positions.xy_to_i(new_x, y);
+                    new_dots.push(new_i);
+                    positions[i] = false;
+                }
+
+                if x == fold {
+                    positions[i] = false;
+                }
+            }
+        }
+
+        for i in new_dots {
+            self.positions[i] = true;
+        }
+
+        self.real_width = fold;
+    }
+
+    fn fold_y(&mut self, fold: usize) {
+        let mut new_dots = vec![];
+
+        let positions = &mut self.positions;
+
+        for i in 0..positions.len() {
+            if positions[i] {
+                let (x, y) = positions.i_to_xy(i);
+                if y > fold {
+                    let diff = y - fold;
+                    let new_y = fold - diff;
+                    let new_i = positions.xy_to_i(x, new_y);
+                    new_dots.push(new_i);
+                    positions[i] = false;
+                }
+
+                if y == fold {
+                    positions[i] = false;
+                }
+            }
+        }
+
+        for i in new_dots {
+            self.positions[i] = true;
+        }
+
+        self.real_height = fold;
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+fn parse_dot(line: &str) -> Result<(usize, usize), ParseError> {
+    let (x, y) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(Day13::DAY, line, 1, "expected `x,y`"))?;
+
+    let x = parse_field(Day13::DAY, line, x)?;
+    let y = parse_field(Day13::DAY, line, y)?;
+
+    Ok((x, y))
+}
+
+fn parse_fold(line: &str) -> Result<Fold, ParseError> {
+    let fold = line
+        .strip_prefix("fold along ")
+        .ok_or_else(|| ParseError::new(Day13::DAY, line, 1, "expected `fold along`"))?;
+
+    match fold.split_once('=') {
+        Some(("x", amount)) => Ok(Fold::X(parse_field(Day13::DAY, line, amount)?)),
+        Some(("y", amount)) => Ok(Fold::Y(parse_field(Day13::DAY, line, amount)?)),
+        _ => Err(ParseError::at(
+            Day13::DAY,
+            line,
+            fold,
+            "expected `x=<n>` or `y=<n>`",
+        )),
+    }
+}
+
+pub struct Day13;
+
+#[derive(Clone)]
+pub struct Instructions {
+    paper: Paper,
+    folds: Vec<Fold>,
+}
+
+impl Solution for Day13 {
+    const DAY: usize = 13;
+
+    type Input = Instructions;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+
+        let mut dots = vec![];
+
+        for (line_i, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+            dots.push(parse_dot(line).map_err(|e| e.on_line(line_i + 1))?);
+        }
+
+        // the paper only needs to be big enough for the furthest dots
+        let width = dots.iter().map(|(x, _)| x + 1).max().unwrap_or(0);
+        let height = dots.iter().map(|(_, y)| y + 1).max().unwrap_or(0);
+
+        let mut paper = Paper {
+            positions: Grid::filled(width, height, false),
+            real_width: width,
+            real_height: height,
+        };
+
+        for (x, y) in dots {
+            paper.positions[(x, y)] = true;
+        }
+
+        let mut folds = vec![];
+
+        for (line_i, fold_line) in lines.filter(|(_, line)| !line.is_empty()) {
+            folds.push(parse_fold(fold_line).map_err(|e| e.on_line(line_i + 1))?);
+        }
+
+        // part 1 is all about the first fold
+        if folds.is_empty() {
+            let line = input.lines().count() + 1;
+            return Err(ParseError::new(Self::DAY, "", 1, "expected a fold").on_line(line));
+        }
+
+        Ok(Instructions { paper, folds })
+    }
+
+    fn part1(instructions: &Self::Input) -> Self::Part1 {
+        let mut paper = instructions.paper.clone();
+
+        paper.fold(&instructions.folds[0]);
+
+        paper
+            .positions
+            .iter()
+            .filter(|is_visible| **is_visible)
+            .count()
+    }
+
+    fn part2(instructions: &Self::Input) -> Self::Part2 {
+        let mut paper = instructions.paper.clone();
+
+        for fold in &instructions.folds {
+            paper.fold(fold);
+        }
+
+        paper.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let input = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+        let instructions = Day13::parse(input).unwrap();
+
+        assert_eq!(Day13::part1(&instructions), 17);
+        assert!(Day13::part2(&instructions).starts_with("# # # # # "));
+    }
+
+    #[test]
+    fn no_folds() {
+        let e = Day13::parse("6,10\n0,14\n").err().unwrap();
+
+        assert_eq!(e.line, 3);
+        assert_eq!(e.reason, "expected a fold");
+    }
+}
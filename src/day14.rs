@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub type PairCounts = HashMap<String, usize>;
+pub type Rules = HashMap<Matcher, Replacement>;
+pub type Matcher = String;
+pub type Replacement = char;
+
+fn parse_rule(line: &str) -> Result<(Matcher, Replacement), ParseError> {
+    let error = |reason| ParseError::new(Day14::DAY, line, 1, reason);
+
+    let (matcher, replacement) = line
+        .split_once(" -> ")
+        .ok_or_else(|| error("expected `AB -> C`"))?;
+
+    if matcher.chars().count() != 2 {
+        return Err(error("a rule matches exactly two elements"));
+    }
+
+    let mut replacement_chars = replacement.chars();
+
+    match (replacement_chars.next(), replacement_chars.next()) {
+        (Some(c), None) => Ok((matcher.to_owned(), c)),
+        _ => Err(ParseError::at(
+            Day14::DAY,
+            line,
+            replacement,
+            "a rule inserts exactly one element",
+        )),
+    }
+}
+
+pub fn replace(s: &str, rules: &Rules) -> String {
+    let v: Vec<_> = s.chars().collect();
+    let as_windows: Vec<_> = v
+        .windows(2)
+        .map(|pair| {
+            let [l, r] = [pair[0], pair[1]];
+            let mut s = String::with_capacity(2);
+            s.push(l);
+            s.push(r);
+            s
+        })
+        .collect();
+
+    let mut replacement_zip = vec!['X'; s.len()];
+
+    for (i, pair) in as_windows.iter().enumerate() {
+        if let Some(replacement) = rules.get(pair) {
+            replacement_zip[i] = *replacement;
+        }
+    }
+
+    let mut out = String::new();
+
+    for (c1, c2) in s.chars().zip(replacement_zip) {
+        out.push(c1);
+        out.push(c2);
+    }
+
+    out.replace('X', "")
+}
+
+/// turn AABC into {A: 2, B: 1, C: 1, AA: 1, AB: 1, BC: 1}
+pub fn to_pair_counts(s: &str) -> PairCounts {
+    let mut out = HashMap::new();
+
+    let chars: Vec<_> = s.chars().collect();
+
+    for pair in chars.windows(2) {
+        let mut pair_string = String::with_capacity(2);
+        pair_string.push(pair[0]);
+        pair_string.push(pair[1]);
+        *out.entry(pair_string).or_insert(0) += 1;
+    }
+
+    for c in chars {
+        let cs = c.to_string();
+        *out.entry(cs).or_insert(0) += 1
+    }
+
+    out
+}
+
+pub fn replace_pairs(s: &PairCounts, rules: &Rules) -> PairCounts {
+    let mut out = HashMap::new();
+
+    // move letters counts over
+    let letters = s.iter().filter(|(k, _v)| k.len() == 1);
+    for (k, v) in letters {
+        out.insert(k.to_owned(), *v);
+    }
+
+    for (pair, count) in s {
+        if pair.len() > 1 {
+            if let Some(replacement) = rules.get(pair) {
+                let chars: Vec<_> = pair.chars().collect();
+
+                let left_char = chars[0].to_string();
+                let center_char = replacement.to_string();
+                let right_char = chars[1].to_string();
+
+                let prev_char_count = *s.get(&center_char).unwrap_or(&0);
+                let e = out
+                    .entry(center_char.to_string())
+                    .or_insert(prev_char_count);
+                *e += count;
+
+                let all = [left_char, center_char, right_char];
+
+                for new_pair in all.windows(2) {
+                    let new_pair = new_pair.join("");
+                    let e = out.entry(new_pair).or_insert(0);
+                    *e += count;
+                }
+            }
+        }
+    }
+
+    out
+}
+
+pub fn max_minus_min(counts: impl Iterator<Item = usize>) -> usize {
+    let mut min = usize::MAX;
+    let mut max = usize::MIN;
+
+    for count in counts {
+        if count > max {
+            max = count;
+        }
+        if count < min {
+            min = count;
+        }
+    }
+
+    max - min
+}
+
+pub struct Day14;
+
+#[derive(Clone, Debug)]
+pub struct Polymer {
+    template: String,
+    rules: Rules,
+}
+
+impl Solution for Day14 {
+    const DAY: usize = 14;
+
+    type Input = Polymer;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+
+        let template = lines.next().map_or("", |(_, line)| line).to_string();
+
+        if template.is_empty() {
+            return Err(ParseError::new(
+                Self::DAY,
+                "",
+                1,
+                "expected a polymer template",
+            ));
+        }
+
+        // skip the blank line
+        lines.next();
+
+        let mut rules = Rules::new();
+
+        for (i, line) in lines.take_while(|(_, line)| !line.is_empty()) {
+            let (matcher, replacement) = parse_rule(line).map_err(|e| e.on_line(i + 1))?;
+
+            rules.insert(matcher, replacement);
+        }
+
+        Ok(Polymer { template, rules })
+    }
+
+    fn part1(polymer: &Self::Input) -> Self::Part1 {
+        let mut template = polymer.template.clone();
+
+        for _step in 0..10 {
+            template = replace(&template, &polymer.rules);
+        }
+
+        let mut counter = HashMap::new();
+
+        for c in template.chars() {
+            let e = counter.entry(c).or_insert(0);
+            *e += 1;
+        }
+
+        max_minus_min(counter.into_values())
+    }
+
+    fn part2(polymer: &Self::Input) -> Self::Part2 {
+        let mut as_pair_counts = to_pair_counts(&polymer.template);
+
+        for _step in 0..40 {
+            as_pair_counts = replace_pairs(&as_pair_counts, &polymer.rules);
+        }
+
+        let chars =
+            as_pair_counts
+                .into_iter()
+                .filter_map(|(k, v)| if k.len() == 1 { Some(v) } else { None });
+
+        max_minus_min(chars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let input = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+        let Polymer {
+            mut template,
+            rules,
+        } = Day14::parse(input).unwrap();
+
+        for _step in 0..2 {
+            template = replace(&template, &rules);
+        }
+
+        assert_eq!(template, "NBCCNBBBCBHCB");
+
+        let mut counter = HashMap::new();
+
+        for c in template.chars() {
+            let e = counter.entry(c).or_insert(0);
+            *e += 1;
+        }
+
+        assert_eq!(max_minus_min(counter.into_values()), 5);
+
+        assert_eq!(Day14::part1(&Day14::parse(input).unwrap()), 1588);
+        assert_eq!(Day14::part2(&Day14::parse(input).unwrap()), 2188189693529);
+    }
+}
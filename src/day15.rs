@@ -0,0 +1,188 @@
+use crate::error::ParseError;
+use crate::grid::{self, Grid};
+use crate::pathfinding::{astar_indexed, dijkstra_indexed, Path};
+use crate::solution::Solution;
+
+/// The full cave: the scanned tile repeated `times` across and down, with
+/// risks one higher for every tile right or down, wrapping from 9 back to 1.
+/// Risks are worked out from the tile when asked for, so the cave itself
+/// costs no more memory than the tile. Searching it still keeps a cost and a
+/// parent for every cell, about 16 bytes each, plus the queue.
+pub struct TiledCave<'a> {
+    tile: &'a Grid<u8>,
+    times: usize,
+}
+
+impl<'a> TiledCave<'a> {
+    pub fn new(tile: &'a Grid<u8>, times: usize) -> Self {
+        Self { tile, times }
+    }
+
+    pub fn width(&self) -> usize {
+        self.tile.width() * self.times
+    }
+
+    pub fn height(&self) -> usize {
+        self.tile.height() * self.times
+    }
+
+    pub fn len(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn i_to_xy(&self, i: usize) -> (usize, usize) {
+        (i % self.width(), i / self.width())
+    }
+
+    pub fn risk(&self, i: usize) -> u8 {
+        let (x, y) = self.i_to_xy(i);
+        let (tile_width, tile_height) = (self.tile.width(), self.tile.height());
+
+        let risk = self.tile[(x % tile_width, y % tile_height)] as usize;
+        let times_to_add_one = x / tile_width + y / tile_height;
+
+        if times_to_add_one == 0 {
+            risk as u8
+        } else {
+            ((risk + times_to_add_one - 1) % 9 + 1) as u8
+        }
+    }
+
+    pub fn neighbours4(&self, i: usize) -> impl Iterator<Item = usize> {
+        grid::neighbours4(self.width(), self.height(), i)
+    }
+
+    // A lower bound on the risk of any cell. Every tile after the first
+    // has been bumped at least once, so nothing there is below 1.
+    fn min_risk(&self) -> usize {
+        let tile_min = self.tile.iter().copied().min().unwrap_or(0) as usize;
+
+        if self.times > 1 {
+            tile_min.min(1)
+        } else {
+            tile_min
+        }
+    }
+}
+
+/// How to look for the path. With risks as low as 1 the A* heuristic is
+/// weak, so it skips hardly any nodes and ends up slower; the puzzle itself
+/// uses Dijkstra.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Search {
+    Dijkstra,
+    AStar,
+}
+
+/// The least risky path from the top left to the bottom right. Entering a
+/// cell costs its risk, so the starting cell is never counted.
+pub fn lowest_risk_path(cave: &TiledCave<'_>, search: Search) -> Path<usize> {
+    let target = cave.len() - 1;
+    let (target_x, target_y) = cave.i_to_xy(target);
+
+    let neighbours = |i: usize| cave.neighbours4(i).map(|j| (j, cave.risk(j) as usize));
+    let is_target = |i: usize| i == target;
+
+    let path = match search {
+        Search::Dijkstra => dijkstra_indexed(cave.len(), 0, neighbours, is_target),
+        Search::AStar => {
+            // Every step costs at least the lowest risk in the cave, so this
+            // never overestimates.
+            let min_risk = cave.min_risk();
+
+            let manhattan = |i: usize| {
+                let (x, y) = cave.i_to_xy(i);
+                min_risk * ((target_x - x) + (target_y - y))
+            };
+
+            astar_indexed(cave.len(), 0, neighbours, manhattan, is_target)
+        }
+    };
+
+    path.expect("every cell of the cave is reachable")
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: usize = 15;
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let risks = Grid::from_digits(Self::DAY, input)?;
+
+        if risks.is_empty() {
+            return Err(ParseError::empty(Self::DAY));
+        }
+
+        Ok(risks)
+    }
+
+    fn part1(risks: &Self::Input) -> Self::Part1 {
+        lowest_risk_path(&TiledCave::new(risks, 1), Search::Dijkstra).cost
+    }
+
+    fn part2(initial_risks_tile: &Self::Input) -> Self::Part2 {
+        let big = TiledCave::new(initial_risks_tile, 5);
+
+        lowest_risk_path(&big, Search::Dijkstra).cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_case() {
+        let input = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+        let risks = Day15::parse(input).unwrap();
+
+        assert_eq!(Day15::part1(&risks), 40);
+        assert_eq!(Day15::part2(&risks), 315);
+    }
+
+    #[test]
+    fn tiling() {
+        let tile = Grid::from_digits(15, "8").unwrap();
+        let cave = TiledCave::new(&tile, 3);
+
+        let risks: Vec<_> = (0..cave.len()).map(|i| cave.risk(i)).collect();
+        assert_eq!(risks, [8, 9, 1, 9, 1, 2, 1, 2, 3]);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Day15::parse("").is_err());
+    }
+
+    #[test]
+    fn astar_expands_less() {
+        let risks = Day15::parse(include_str!("../inputs/15.txt")).unwrap();
+
+        let cave = TiledCave::new(&risks, 1);
+
+        let plain = lowest_risk_path(&cave, Search::Dijkstra);
+        let guided = lowest_risk_path(&cave, Search::AStar);
+
+        assert_eq!(plain.cost, guided.cost);
+        assert!(guided.expanded <= plain.expanded);
+    }
+}
@@ -0,0 +1,1025 @@
+use std::fmt::Display;
+
+use crate::bigint::BigUint;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Packet {
+    Literal {
+        header: Header,
+        value: usize,
+    },
+    Operator {
+        header: Header,
+        op: Op,
+        packets: Vec<Packet>,
+    },
+}
+
+/// What an operator packet does with the values of its sub-packets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Gt,
+    Lt,
+    Eq,
+}
+
+impl Op {
+    /// The operator a packet type ID stands for. Type ID 4 is a literal, so
+    /// it has none.
+    pub fn from_type_id(type_id: usize) -> Option<Self> {
+        match type_id {
+            0 => Some(Op::Sum),
+            1 => Some(Op::Product),
+            2 => Some(Op::Min),
+            3 => Some(Op::Max),
+            5 => Some(Op::Gt),
+            6 => Some(Op::Lt),
+            7 => Some(Op::Eq),
+            _ => None,
+        }
+    }
+
+    pub fn type_id(self) -> usize {
+        match self {
+            Op::Sum => 0,
+            Op::Product => 1,
+            Op::Min => 2,
+            Op::Max => 3,
+            Op::Gt => 5,
+            Op::Lt => 6,
+            Op::Eq => 7,
+        }
+    }
+
+    /// Whether `n` sub-packets make sense for this operator.
+    fn takes(self, n: usize) -> bool {
+        match self {
+            Op::Sum | Op::Product => true,
+            Op::Min | Op::Max => n > 0,
+            Op::Gt | Op::Lt | Op::Eq => n == 2,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Sum => "+",
+            Op::Product => "*",
+            Op::Min => "min",
+            Op::Max => "max",
+            Op::Gt => ">",
+            Op::Lt => "<",
+            Op::Eq => "==",
+        }
+    }
+}
+
+impl Packet {
+    pub fn version_sum(&self) -> usize {
+        let mut s = 0;
+        match self {
+            Packet::Literal { header, .. } => s += header.version,
+            Packet::Operator {
+                header, packets, ..
+            } => {
+                s += header.version;
+
+                for packet in packets {
+                    s += packet.version_sum();
+                }
+            }
+        }
+        s
+    }
+
+    /// The value of the expression, unless working it out overflows a
+    /// `usize` somewhere along the way.
+    pub fn score(&self) -> Result<usize, EvalError> {
+        let (op, packets) = match self {
+            Packet::Literal { value, .. } => return Ok(*value),
+            Packet::Operator { op, packets, .. } => (*op, packets),
+        };
+
+        let scores = packets
+            .iter()
+            .map(|packet| packet.score())
+            .collect::<Result<Vec<_>, _>>()?;
+        let overflow = EvalError::Overflow { op };
+        let operands = EvalError::Operands {
+            op,
+            count: scores.len(),
+        };
+
+        match (op, scores.as_slice()) {
+            (Op::Sum, _) => scores
+                .iter()
+                .try_fold(0usize, |s, score| s.checked_add(*score))
+                .ok_or(overflow),
+            (Op::Product, _) => scores
+                .iter()
+                .try_fold(1usize, |s, score| s.checked_mul(*score))
+                .ok_or(overflow),
+            (Op::Min, _) => scores.iter().copied().min().ok_or(operands),
+            (Op::Max, _) => scores.iter().copied().max().ok_or(operands),
+            (Op::Gt, [a, b]) => Ok((a > b) as usize),
+            (Op::Lt, [a, b]) => Ok((a < b) as usize),
+            (Op::Eq, [a, b]) => Ok((a == b) as usize),
+            (Op::Gt | Op::Lt | Op::Eq, _) => Err(operands),
+        }
+    }
+
+    /// Like `score`, but with as many digits as it takes so it can't
+    /// overflow.
+    pub fn big_score(&self) -> Result<BigUint, EvalError> {
+        let (op, packets) = match self {
+            Packet::Literal { value, .. } => return Ok(BigUint::from(*value)),
+            Packet::Operator { op, packets, .. } => (*op, packets),
+        };
+
+        let scores = packets
+            .iter()
+            .map(|packet| packet.big_score())
+            .collect::<Result<Vec<_>, _>>()?;
+        let operands = EvalError::Operands {
+            op,
+            count: scores.len(),
+        };
+
+        match (op, scores.as_slice()) {
+            (Op::Sum, _) => Ok(scores.iter().fold(BigUint::zero(), |s, score| &s + score)),
+            (Op::Product, _) => Ok(scores.iter().fold(BigUint::one(), |s, score| &s * score)),
+            (Op::Min, _) => scores.into_iter().min().ok_or(operands),
+            (Op::Max, _) => scores.into_iter().max().ok_or(operands),
+            (Op::Gt, [a, b]) => Ok(BigUint::from((a > b) as usize)),
+            (Op::Lt, [a, b]) => Ok(BigUint::from((a < b) as usize)),
+            (Op::Eq, [a, b]) => Ok(BigUint::from((a == b) as usize)),
+            (Op::Gt | Op::Lt | Op::Eq, _) => Err(operands),
+        }
+    }
+}
+
+/// Why a packet has no value.
+#[derive(Debug, PartialEq, Eq)]
+pub enum EvalError {
+    /// Working it out went past what a `usize` can hold.
+    Overflow { op: Op },
+    /// An operator with a number of sub-packets it can't take. The parser
+    /// never lets these through, but a packet built by hand can have one.
+    Operands { op: Op, count: usize },
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Overflow { op } => write!(f, "a {:?} packet overflowed a usize", op),
+            EvalError::Operands { op, count } => {
+                write!(f, "a {:?} packet can't take {} sub-packets", op, count)
+            }
+        }
+    }
+}
+
+/// How `Packet::pretty` lays out a packet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
+    /// Prefix with everything in brackets, like `(+ 1 (* 2 3))`.
+    SExpr,
+    /// The way it'd be written by hand, like `1 + (2 * 3)`.
+    Infix,
+}
+
+/// A packet laid out as a formula, for seeing what a transmission computes.
+pub struct Pretty<'a> {
+    packet: &'a Packet,
+    style: Style,
+    versions: bool,
+}
+
+impl Packet {
+    pub fn pretty(&self, style: Style) -> Pretty<'_> {
+        Pretty {
+            packet: self,
+            style,
+            versions: false,
+        }
+    }
+}
+
+impl<'a> Pretty<'a> {
+    /// Marks every packet with its version, as in `v1:+`.
+    pub fn with_versions(self) -> Self {
+        Self {
+            versions: true,
+            ..self
+        }
+    }
+
+    fn nested(&self, packet: &'a Packet) -> Self {
+        Self { packet, ..*self }
+    }
+
+    fn version(&self, f: &mut std::fmt::Formatter<'_>, header: &Header) -> std::fmt::Result {
+        if self.versions {
+            write!(f, "v{}:", header.version)?;
+        }
+
+        Ok(())
+    }
+
+    fn sexpr(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.packet {
+            Packet::Literal { header, value } => {
+                self.version(f, header)?;
+                write!(f, "{}", value)
+            }
+            Packet::Operator {
+                header,
+                op,
+                packets,
+            } => {
+                write!(f, "(")?;
+                self.version(f, header)?;
+                write!(f, "{}", op.symbol())?;
+
+                for packet in packets {
+                    write!(f, " ")?;
+                    self.nested(packet).sexpr(f)?;
+                }
+
+                write!(f, ")")
+            }
+        }
+    }
+
+    fn infix(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (header, op, packets) = match self.packet {
+            Packet::Literal { header, value } => {
+                self.version(f, header)?;
+                return write!(f, "{}", value);
+            }
+            Packet::Operator {
+                header,
+                op,
+                packets,
+            } => (header, *op, packets),
+        };
+
+        if let Op::Min | Op::Max = op {
+            self.version(f, header)?;
+            write!(f, "{}(", op.symbol())?;
+
+            for (i, packet) in packets.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+
+                self.nested(packet).infix(f)?;
+            }
+
+            return write!(f, ")");
+        }
+
+        match packets.as_slice() {
+            // nothing to put the operator between, so just the value
+            [] => {
+                self.version(f, header)?;
+                write!(f, "{}", if op == Op::Sum { 0 } else { 1 })
+            }
+            [packet] if !self.versions => self.nested(packet).operand(f),
+            // the operator's version needs somewhere to go, so it's called
+            // like a function instead
+            [packet] => {
+                self.version(f, header)?;
+                write!(f, "{}(", op.symbol())?;
+                self.nested(packet).infix(f)?;
+                write!(f, ")")
+            }
+            _ => {
+                for (i, packet) in packets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                        self.version(f, header)?;
+                        write!(f, "{} ", op.symbol())?;
+                    }
+
+                    self.nested(packet).operand(f)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    // An operand of an infix operator, bracketed unless it's a single term.
+    fn operand(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.packet {
+            Packet::Operator {
+                op: Op::Sum | Op::Product | Op::Gt | Op::Lt | Op::Eq,
+                packets,
+                ..
+            } if packets.len() > 1 => {
+                write!(f, "(")?;
+                self.infix(f)?;
+                write!(f, ")")
+            }
+            _ => self.infix(f),
+        }
+    }
+}
+
+impl Display for Pretty<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.style {
+            Style::SExpr => self.sexpr(f),
+            Style::Infix => self.infix(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    pub version: usize,
+}
+
+/// A problem partway through a transmission, `position` bits in.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BitsError {
+    pub position: usize,
+    pub reason: String,
+}
+
+/// Reads a transmission a few bits at a time, most significant bit first.
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() * 8 - self.position
+    }
+
+    /// The next `n` bits as a number. `n` can't be more than fits in a
+    /// `usize`.
+    pub fn read_bits(&mut self, n: usize) -> Result<usize, BitsError> {
+        assert!(n <= usize::BITS as usize, "can't read {} bits at once", n);
+
+        if n > self.remaining() {
+            return Err(self.error(format!(
+                "expected {} more bits, found {}",
+                n,
+                self.remaining()
+            )));
+        }
+
+        let mut value = 0;
+
+        for _ in 0..n {
+            let byte = self.bytes[self.position / 8];
+            let bit = (byte >> (7 - self.position % 8)) & 1;
+
+            value = value << 1 | bit as usize;
+            self.position += 1;
+        }
+
+        Ok(value)
+    }
+
+    fn error(&self, reason: String) -> BitsError {
+        BitsError {
+            position: self.position,
+            reason,
+        }
+    }
+}
+
+pub fn parse_packet(bits: &mut BitReader<'_>) -> Result<Packet, BitsError> {
+    let start = bits.position();
+    let version = bits.read_bits(3)?;
+    let type_id = bits.read_bits(3)?;
+
+    let header = Header { version };
+
+    if type_id == 4 {
+        let value = parse_literal(bits)?;
+        return Ok(Packet::Literal { header, value });
+    }
+
+    let op = Op::from_type_id(type_id).ok_or_else(|| BitsError {
+        position: start + 3,
+        reason: format!("{} is not a packet type ID", type_id),
+    })?;
+
+    let packets = parse_operator(bits)?;
+
+    if !op.takes(packets.len()) {
+        return Err(BitsError {
+            position: start,
+            reason: format!("{:?} can't take {} sub-packets", op, packets.len()),
+        });
+    }
+
+    Ok(Packet::Operator {
+        header,
+        op,
+        packets,
+    })
+}
+
+fn parse_literal(bits: &mut BitReader<'_>) -> Result<usize, BitsError> {
+    let start = bits.position();
+    let mut value: usize = 0;
+
+    loop {
+        let more = bits.read_bits(1)?;
+        let four_bits = bits.read_bits(4)?;
+
+        if value.leading_zeros() < 4 {
+            return Err(BitsError {
+                position: start,
+                reason: "literal is too wide for a usize".to_string(),
+            });
+        }
+
+        value = value << 4 | four_bits;
+
+        if more == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+fn parse_operator(bits: &mut BitReader<'_>) -> Result<Vec<Packet>, BitsError> {
+    let length_type_id = bits.read_bits(1)?;
+
+    let mut packets = vec![];
+
+    if length_type_id == 0 {
+        let total_length_in_bits = bits.read_bits(15)?;
+
+        if total_length_in_bits > bits.remaining() {
+            return Err(bits.error(format!(
+                "expected {} more bits of sub-packets, found {}",
+                total_length_in_bits,
+                bits.remaining()
+            )));
+        }
+
+        let end = bits.position() + total_length_in_bits;
+
+        while bits.position() < end {
+            packets.push(parse_packet(bits)?);
+        }
+
+        if bits.position() > end {
+            return Err(bits.error(format!("sub-packets ran past bit {}", end)));
+        }
+    } else {
+        let number_of_sub_packets = bits.read_bits(11)?;
+
+        for _i in 0..number_of_sub_packets {
+            packets.push(parse_packet(bits)?);
+        }
+    }
+
+    Ok(packets)
+}
+
+/// Two hex digits to a byte. An odd digit out is padded with zeros, which
+/// a transmission ignores anyway.
+pub fn hex_to_bytes(s: &str) -> Result<Vec<u8>, ParseError> {
+    let nibbles = s
+        .char_indices()
+        .map(|(i, c)| match c.to_digit(16) {
+            Some(nibble) => Ok(nibble as u8),
+            None => Err(ParseError::at(
+                Day16::DAY,
+                s,
+                &s[i..],
+                format!("{:?} is not a hex digit", c),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(nibbles
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair.get(1).copied().unwrap_or(0))
+        .collect())
+}
+
+/// Which of the two ways an operator says how long its sub-packets are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthType {
+    /// Length type ID 0: the total number of bits in the sub-packets.
+    Bits,
+    /// Length type ID 1: the number of sub-packets.
+    Count,
+}
+
+/// The opposite of `BitReader`, building up a transmission a few bits at a
+/// time.
+#[derive(Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Writes `value` as `n` bits. It's an error if it needs more than that.
+    pub fn write_bits(&mut self, value: usize, n: usize) -> Result<(), BitsError> {
+        if n < usize::BITS as usize && value >> n != 0 {
+            return Err(BitsError {
+                position: self.len,
+                reason: format!("{} does not fit in {} bits", value, n),
+            });
+        }
+
+        for i in (0..n).rev() {
+            let bit = (value >> i) as u8 & 1;
+            self.push_bit(bit);
+        }
+
+        Ok(())
+    }
+
+    /// Writes everything from `other` after what's already here.
+    pub fn append(&mut self, other: &BitWriter) {
+        for i in 0..other.len {
+            let bit = (other.bytes[i / 8] >> (7 - i % 8)) & 1;
+            self.push_bit(bit);
+        }
+    }
+
+    /// The transmission so far, padded with zeros to a whole byte.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    fn push_bit(&mut self, bit: u8) {
+        if self.len == self.bytes.len() * 8 {
+            self.bytes.push(0);
+        }
+
+        self.bytes[self.len / 8] |= bit << (7 - self.len % 8);
+        self.len += 1;
+    }
+}
+
+/// Writes `packet` as a transmission, giving every operator's length as
+/// `length_type`.
+pub fn encode_packet(
+    packet: &Packet,
+    length_type: LengthType,
+    bits: &mut BitWriter,
+) -> Result<(), BitsError> {
+    match packet {
+        Packet::Literal { header, value } => {
+            bits.write_bits(header.version, 3)?;
+            bits.write_bits(4, 3)?;
+
+            // as few groups as will hold the value, but always at least one
+            let width = usize::BITS - value.leading_zeros();
+            let groups = width.div_ceil(4).max(1);
+
+            for group in (0..groups).rev() {
+                let more = if group == 0 { 0 } else { 1 };
+                bits.write_bits(more, 1)?;
+                bits.write_bits(value >> (group * 4) & 0xF, 4)?;
+            }
+        }
+        Packet::Operator {
+            header,
+            op,
+            packets,
+        } => {
+            // nothing the parser would reject gets written
+            if !op.takes(packets.len()) {
+                return Err(BitsError {
+                    position: bits.len(),
+                    reason: format!("{:?} can't take {} sub-packets", op, packets.len()),
+                });
+            }
+
+            bits.write_bits(header.version, 3)?;
+            bits.write_bits(op.type_id(), 3)?;
+
+            match length_type {
+                LengthType::Bits => {
+                    // the length comes first, so the sub-packets are written
+                    // on the side to find out what it is
+                    let mut sub_packets = BitWriter::new();
+
+                    for packet in packets {
+                        encode_packet(packet, length_type, &mut sub_packets)?;
+                    }
+
+                    bits.write_bits(0, 1)?;
+                    bits.write_bits(sub_packets.len(), 15)?;
+                    bits.append(&sub_packets);
+                }
+                LengthType::Count => {
+                    bits.write_bits(1, 1)?;
+                    bits.write_bits(packets.len(), 11)?;
+
+                    for packet in packets {
+                        encode_packet(packet, length_type, bits)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `packet` as the hex a transmission would arrive as.
+pub fn to_hex(packet: &Packet, length_type: LengthType) -> Result<String, BitsError> {
+    let mut bits = BitWriter::new();
+    encode_packet(packet, length_type, &mut bits)?;
+
+    Ok(bits
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: usize = 16;
+
+    type Input = Packet;
+    type Part1 = usize;
+    type Part2 = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let hex = input.trim();
+        let bytes = hex_to_bytes(hex)?;
+
+        parse_packet(&mut BitReader::new(&bytes)).map_err(|e| {
+            let reason = format!("at bit {}, {}", e.position, e.reason);
+            ParseError::new(Self::DAY, hex, e.position / 4 + 1, reason)
+        })
+    }
+
+    fn part1(packet: &Self::Input) -> Self::Part1 {
+        packet.version_sum()
+    }
+
+    fn part2(packet: &Self::Input) -> Self::Part2 {
+        // only bother with big numbers when a transmission needs them
+        packet
+            .score()
+            .map(BigUint::from)
+            .or_else(|_| packet.big_score())
+            .expect("the parser checked how many sub-packets each operator has")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant(value: usize) -> Packet {
+        Packet::Literal {
+            header: Header { version: 0 },
+            value,
+        }
+    }
+
+    fn operator(op: Op, packets: Vec<Packet>) -> Packet {
+        Packet::Operator {
+            header: Header { version: 0 },
+            op,
+            packets,
+        }
+    }
+
+    #[test]
+    fn literal() {
+        let hex = "D2FE28";
+        let bytes = hex_to_bytes(hex).unwrap();
+        let packet = parse_packet(&mut BitReader::new(&bytes)).unwrap();
+        assert_eq!(
+            packet,
+            Packet::Literal {
+                header: Header { version: 6 },
+                value: 2021
+            }
+        )
+    }
+
+    #[test]
+    fn operator_zero() {
+        let hex = "38006F45291200";
+        let bytes = hex_to_bytes(hex).unwrap();
+        let packet = parse_packet(&mut BitReader::new(&bytes)).unwrap();
+        assert_eq!(
+            packet,
+            Packet::Operator {
+                header: Header { version: 1 },
+                op: Op::Lt,
+                packets: vec![
+                    Packet::Literal {
+                        header: Header { version: 6 },
+                        value: 10
+                    },
+                    Packet::Literal {
+                        header: Header { version: 2 },
+                        value: 20
+                    }
+                ],
+            }
+        )
+    }
+
+    #[test]
+    fn operator_one() {
+        let hex = "EE00D40C823060";
+        let bytes = hex_to_bytes(hex).unwrap();
+        let packet = parse_packet(&mut BitReader::new(&bytes)).unwrap();
+        assert_eq!(
+            packet,
+            Packet::Operator {
+                header: Header { version: 7 },
+                op: Op::Max,
+                packets: vec![
+                    Packet::Literal {
+                        header: Header { version: 2 },
+                        value: 1
+                    },
+                    Packet::Literal {
+                        header: Header { version: 4 },
+                        value: 2
+                    },
+                    Packet::Literal {
+                        header: Header { version: 1 },
+                        value: 3
+                    }
+                ],
+            }
+        )
+    }
+
+    #[test]
+    fn lowercase_hex() {
+        assert_eq!(Day16::parse("d2fe28"), Day16::parse("D2FE28"));
+    }
+
+    #[test]
+    fn truncated() {
+        let e = Day16::parse("38006F4529").unwrap_err();
+        assert_eq!(e.day, 16);
+        assert!(e.reason.contains("more bits"), "{}", e.reason);
+    }
+
+    #[test]
+    fn encode_samples() {
+        let decode = |hex| Day16::parse(hex).unwrap();
+
+        let packet = decode("38006F45291200");
+        assert_eq!(to_hex(&packet, LengthType::Bits).unwrap(), "38006F45291200");
+
+        let packet = decode("EE00D40C823060");
+        assert_eq!(
+            to_hex(&packet, LengthType::Count).unwrap(),
+            "EE00D40C823060"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let packet = Day16::parse(include_str!("../inputs/16.txt")).unwrap();
+
+        for length_type in [LengthType::Bits, LengthType::Count] {
+            let hex = to_hex(&packet, length_type).unwrap();
+            assert_eq!(Day16::parse(&hex).unwrap(), packet);
+        }
+    }
+
+    #[test]
+    fn encode_by_hand() {
+        // 1 + 2 * 3
+        let packet = operator(
+            Op::Sum,
+            vec![
+                constant(1),
+                operator(Op::Product, vec![constant(2), constant(3)]),
+            ],
+        );
+
+        let hex = to_hex(&packet, LengthType::Bits).unwrap();
+        assert_eq!(
+            Day16::part2(&Day16::parse(&hex).unwrap()).to_usize(),
+            Some(7)
+        );
+
+        let too_new = Packet::Literal {
+            header: Header { version: 8 },
+            value: 0,
+        };
+        assert!(to_hex(&too_new, LengthType::Count).is_err());
+    }
+
+    #[test]
+    fn type_ids() {
+        for type_id in 0..8 {
+            match Op::from_type_id(type_id) {
+                Some(op) => assert_eq!(op.type_id(), type_id),
+                None => assert_eq!(type_id, 4),
+            }
+        }
+    }
+
+    #[test]
+    fn comparison_operands() {
+        let three = operator(Op::Gt, vec![constant(3), constant(2), constant(1)]);
+        assert!(to_hex(&three, LengthType::Count).is_err());
+
+        // so the transmission has to be put together by hand
+        let mut bits = BitWriter::new();
+        bits.write_bits(0, 3).unwrap();
+        bits.write_bits(Op::Gt.type_id(), 3).unwrap();
+        bits.write_bits(1, 1).unwrap();
+        bits.write_bits(3, 11).unwrap();
+
+        for value in [3, 2, 1] {
+            encode_packet(&constant(value), LengthType::Count, &mut bits).unwrap();
+        }
+
+        let hex: String = bits
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect();
+        let e = Day16::parse(&hex).unwrap_err();
+        assert!(
+            e.reason.contains("can't take 3 sub-packets"),
+            "{}",
+            e.reason
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let packet = Day16::parse("9C0141080250320F1802104A08").unwrap();
+
+        assert_eq!(
+            packet.pretty(Style::SExpr).to_string(),
+            "(== (+ 1 3) (* 2 2))"
+        );
+        assert_eq!(
+            packet.pretty(Style::Infix).to_string(),
+            "(1 + 3) == (2 * 2)"
+        );
+
+        let packet = Day16::parse("880086C3E88112").unwrap();
+        assert_eq!(packet.pretty(Style::Infix).to_string(), "min(7, 8, 9)");
+        assert_eq!(
+            packet.pretty(Style::SExpr).with_versions().to_string(),
+            "(v4:min v5:7 v6:8 v0:9)"
+        );
+    }
+
+    #[test]
+    fn pretty_infix_versions() {
+        let packet = Day16::parse("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(
+            packet.pretty(Style::Infix).with_versions().to_string(),
+            "(v2:1 v2:+ v4:3) v4:== (v0:2 v6:* v2:2)"
+        );
+
+        let single = Packet::Operator {
+            header: Header { version: 5 },
+            op: Op::Sum,
+            packets: vec![Packet::Literal {
+                header: Header { version: 1 },
+                value: 7,
+            }],
+        };
+        assert_eq!(single.pretty(Style::Infix).to_string(), "7");
+        assert_eq!(
+            single.pretty(Style::Infix).with_versions().to_string(),
+            "v5:+(v1:7)"
+        );
+
+        let empty = Packet::Operator {
+            header: Header { version: 3 },
+            op: Op::Product,
+            packets: vec![],
+        };
+        let nested = operator(Op::Product, vec![single, empty, constant(2)]);
+        assert_eq!(
+            nested.pretty(Style::Infix).with_versions().to_string(),
+            "v5:+(v1:7) v0:* v3:1 v0:* v0:2"
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let packet = operator(Op::Product, vec![constant(usize::MAX), constant(4)]);
+
+        assert_eq!(packet.score(), Err(EvalError::Overflow { op: Op::Product }));
+        assert_eq!(
+            packet.big_score(),
+            Ok(&BigUint::from(usize::MAX) * &BigUint::from(4))
+        );
+
+        // the answer fits, but the checked evaluation still overflows on the way
+        let compared = operator(Op::Gt, vec![packet.clone(), constant(1)]);
+        assert_eq!(
+            compared.score(),
+            Err(EvalError::Overflow { op: Op::Product })
+        );
+        assert_eq!(compared.big_score(), Ok(BigUint::one()));
+
+        let hex = to_hex(&packet, LengthType::Count).unwrap();
+        let answer = Day16::part2(&Day16::parse(&hex).unwrap());
+        assert_eq!(answer.to_string(), (usize::MAX as u128 * 4).to_string());
+    }
+
+    #[test]
+    fn bad_operands() {
+        let empty = operator(Op::Min, vec![]);
+        let operands = EvalError::Operands {
+            op: Op::Min,
+            count: 0,
+        };
+
+        assert_eq!(empty.score(), Err(operands));
+        assert!(empty.big_score().is_err());
+        assert!(to_hex(&empty, LengthType::Bits).is_err());
+
+        let one = operator(Op::Eq, vec![constant(1)]);
+        let nested = operator(Op::Sum, vec![one]);
+
+        assert_eq!(
+            nested.score(),
+            Err(EvalError::Operands {
+                op: Op::Eq,
+                count: 1
+            })
+        );
+        assert!(nested.big_score().is_err());
+        assert!(to_hex(&nested, LengthType::Count).is_err());
+    }
+
+    #[test]
+    fn read_bits() {
+        let mut bits = BitReader::new(&[0b1010_1100, 0b0101_0011]);
+
+        assert_eq!(bits.read_bits(3), Ok(0b101));
+        assert_eq!(bits.read_bits(9), Ok(0b0_1100_0101));
+        assert_eq!(bits.remaining(), 4);
+        assert!(bits.read_bits(5).is_err());
+    }
+
+    #[test]
+    fn wide_literal() {
+        // version 0, type 4, then 17 groups of four 1s: 68 bits of value
+        let groups = "11111".repeat(16) + "01111";
+        let mut bin = format!("000100{}", groups);
+        bin.push_str(&"0".repeat(8 - bin.len() % 8));
+
+        let bytes: Vec<u8> = bin
+            .as_bytes()
+            .chunks(8)
+            .map(|byte| u8::from_str_radix(std::str::from_utf8(byte).unwrap(), 2).unwrap())
+            .collect();
+
+        let e = parse_packet(&mut BitReader::new(&bytes)).unwrap_err();
+        assert_eq!(e.position, 6);
+    }
+
+    #[test]
+    fn not_hex() {
+        let e = Day16::parse("D2FG28").unwrap_err();
+        assert_eq!(e.column, 4);
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_field, ParseError};
+use crate::solution::Solution;
+
+#[derive(Clone, Copy, Debug)]
+struct Probe {
+    position: Position,
+    velocity: Velocity,
+    max_y: isize,
+}
+
+impl Probe {
+    fn lands_within_target_area(&mut self, target_area: &TargetArea) -> Lands {
+        loop {
+            self.step();
+
+            if target_area.is_within(self) {
+                return Lands::Within;
+            }
+
+            if self.position.x > target_area.xmax {
+                return Lands::Right;
+            }
+
+            if self.position.x < target_area.xmin && self.position.y < target_area.ymin {
+                return Lands::Left;
+            }
+
+            if self.position.y < target_area.ymin {
+                return Lands::Below;
+            }
+        }
+    }
+
+    fn step(&mut self) {
+        // The probe's x position increases by its x velocity.
+        self.position.x += self.velocity.x;
+        // The probe's y position increases by its y velocity.
+        self.position.y += self.velocity.y;
+
+        if self.position.y > self.max_y {
+            self.max_y = self.position.y
+        }
+
+        // Due to drag, the probe's x velocity changes by 1 toward the value 0;
+        // that is, it decreases by 1 if it is greater than 0, increases by 1 if it is less than 0, or does not change if it is already 0.
+        match self.velocity.x.cmp(&0) {
+            std::cmp::Ordering::Greater => {
+                self.velocity.x -= 1;
+            }
+            std::cmp::Ordering::Less => {
+                self.velocity.x += 1;
+            }
+            std::cmp::Ordering::Equal => (),
+        }
+
+        // Due to gravity, the probe's y velocity decreases by 1.
+        self.velocity.y -= 1
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+struct Velocity {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Position {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Clone, Debug)]
+pub struct TargetArea {
+    xmin: isize,
+    xmax: isize,
+    ymin: isize,
+    ymax: isize,
+}
+
+impl TargetArea {
+    fn is_within(&self, probe: &Probe) -> bool {
+        probe.position.x >= self.xmin
+            && probe.position.x <= self.xmax
+            && probe.position.y >= self.ymin
+            && probe.position.y <= self.ymax
+    }
+}
+
+enum Lands {
+    Left,
+    Right,
+    Below,
+    Within,
+}
+
+fn optimize_for_height(target_area: &TargetArea, initial_v: Velocity, max_runs: usize) -> isize {
+    let mut max_y = 0;
+    let mut current_v = initial_v;
+
+    for _run in 0..max_runs {
+        let mut probe = Probe {
+            position: Position::default(),
+            velocity: current_v,
+            max_y: 0,
+        };
+
+        match probe.lands_within_target_area(target_area) {
+            Lands::Left => {
+                current_v.x += 1;
+
+                current_v.y += 1;
+            }
+            Lands::Right => {
+                current_v.x -= 1;
+                current_v.y += 1;
+            }
+            Lands::Below => {
+                current_v.y += 1;
+            }
+            Lands::Within => {
+                if probe.max_y > max_y {
+                    max_y = probe.max_y;
+                }
+
+                current_v.y += 1;
+            }
+        }
+    }
+
+    max_y
+}
+
+fn find_good_shots(
+    target_area: &TargetArea,
+    xmin: isize,
+    ymin: isize,
+    xmax: isize,
+    ymax: isize,
+) -> HashSet<Velocity> {
+    let mut good_shots = HashSet::new();
+
+    for x in xmin..=xmax {
+        for y in ymin..=ymax {
+            let v = Velocity { x, y };
+            let mut probe = Probe {
+                position: Position::default(),
+                velocity: v,
+                max_y: 0,
+            };
+
+            if let Lands::Within = probe.lands_within_target_area(target_area) {
+                good_shots.insert(v);
+            }
+        }
+    }
+
+    good_shots
+}
+
+fn parse_range(line: &str, s: &str, prefix: &str) -> Result<(isize, isize), ParseError> {
+    let (min, max) = s
+        .strip_prefix(prefix)
+        .and_then(|range| range.split_once(".."))
+        .ok_or_else(|| {
+            let reason = format!("expected `{}<min>..<max>`", prefix);
+            ParseError::at(Day17::DAY, line, s, reason)
+        })?;
+
+    Ok((
+        parse_field(Day17::DAY, line, min)?,
+        parse_field(Day17::DAY, line, max)?,
+    ))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: usize = 17;
+
+    type Input = TargetArea;
+    type Part1 = isize;
+    type Part2 = usize;
+
+    // target area: x=153..199, y=-114..-75
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.lines().next().unwrap_or("");
+        let error = |at: &str, reason: &str| ParseError::at(Self::DAY, line, at, reason);
+
+        let ranges = line
+            .strip_prefix("target area: ")
+            .ok_or_else(|| error(line, "expected `target area: `"))?;
+        let (xs, ys) = ranges
+            .split_once(", ")
+            .ok_or_else(|| error(ranges, "expected `x=<min>..<max>, y=<min>..<max>`"))?;
+
+        let (xmin, xmax) = parse_range(line, xs, "x=")?;
+        let (ymin, ymax) = parse_range(line, ys, "y=")?;
+
+        Ok(TargetArea {
+            xmin,
+            xmax,
+            ymin,
+            ymax,
+        })
+    }
+
+    fn part1(target_area: &Self::Input) -> Self::Part1 {
+        optimize_for_height(target_area, Velocity { x: 4, y: 4 }, 10_000)
+    }
+
+    fn part2(target_area: &Self::Input) -> Self::Part2 {
+        let max_y = optimize_for_height(target_area, Velocity { x: 4, y: 4 }, 10_000);
+
+        let good_shots = find_good_shots(target_area, 0, target_area.ymin, target_area.xmax, max_y);
+
+        good_shots.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let target_area = Day17::parse("target area: x=20..30, y=-10..-5").unwrap();
+        assert_eq!((target_area.xmin, target_area.ymax), (20, -5));
+
+        let e = Day17::parse("target area: x=20..30, y=-10..z").unwrap_err();
+        assert_eq!(e.column, 31);
+    }
+}
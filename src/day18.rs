@@ -0,0 +1,816 @@
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+use std::thread;
+
+use crate::error::{parse_field, parse_lines, ParseError};
+use crate::solution::Solution;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlatNumbers {
+    values: Vec<usize>,
+    depths: Vec<usize>,
+}
+
+impl FlatNumbers {
+    pub fn magnitude(&self) -> usize {
+        // (magnitude, depth) of the pairs still waiting for their right half
+        let mut stack: Vec<(usize, usize)> = vec![];
+
+        for (&value, &depth) in self.values.iter().zip(&self.depths) {
+            stack.push((value, depth));
+
+            while let [.., (left, left_depth), (right, right_depth)] = stack[..] {
+                if left_depth != right_depth {
+                    break;
+                }
+
+                stack.truncate(stack.len() - 2);
+                stack.push((3 * left + 2 * right, left_depth - 1));
+            }
+        }
+
+        stack[0].0
+    }
+
+    /// The pair of `self` and `other`, without reducing it.
+    pub fn concat(&self, other: &Self) -> Self {
+        let values = self.values.iter().chain(&other.values).copied().collect();
+        let depths = self
+            .depths
+            .iter()
+            .chain(&other.depths)
+            .map(|depth| depth + 1)
+            .collect();
+
+        Self { values, depths }
+    }
+
+    /// Explodes and splits until neither applies, in the same order as the
+    /// puzzle but in a single pass where it can.
+    pub fn reduce(&mut self) {
+        // The single pass relies on each exploding pair being two regular
+        // numbers, which holds when adding numbers that were already reduced.
+        if self.depths.iter().any(|depth| *depth > 5) {
+            return self.reduce_stepwise();
+        }
+
+        // First every pair that starts too deep explodes, left to right.
+        // Splits can wait, since explodes always come first.
+        let mut values = Vec::with_capacity(self.values.len());
+        let mut depths = Vec::with_capacity(self.depths.len());
+        let mut carry = 0;
+        let mut i = 0;
+
+        while i < self.values.len() {
+            let value = self.values[i] + carry;
+            carry = 0;
+
+            if self.depths[i] == 5 {
+                if let Some(left) = values.last_mut() {
+                    *left += value;
+                }
+
+                carry = self.values[i + 1];
+                values.push(0);
+                depths.push(4);
+                i += 2;
+            } else {
+                values.push(value);
+                depths.push(self.depths[i]);
+                i += 1;
+            }
+        }
+
+        // Then the splits, keeping everything to the left of the current
+        // number done. Splitting at depth 4 explodes straight away, which
+        // can push the number to its left back over 9, so that one goes back
+        // to be looked at again. `rest` is in reverse to pop from the left.
+        let mut rest: Vec<(usize, usize)> = values.into_iter().zip(depths).rev().collect();
+
+        self.values.clear();
+        self.depths.clear();
+
+        while let Some((value, depth)) = rest.pop() {
+            if value < 10 {
+                self.values.push(value);
+                self.depths.push(depth);
+            } else if depth < 4 {
+                rest.push((value - value / 2, depth + 1));
+                rest.push((value / 2, depth + 1));
+            } else {
+                if let Some((right, _)) = rest.last_mut() {
+                    *right += value - value / 2;
+                }
+
+                rest.push((0, depth));
+
+                if let Some(left) = self.values.last_mut() {
+                    *left += value / 2;
+
+                    if *left >= 10 {
+                        rest.push((self.values.pop().unwrap(), self.depths.pop().unwrap()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// `reduce` done the way the puzzle describes it, one explode or split
+    /// at a time.
+    pub fn reduce_stepwise(&mut self) {
+        while self.step().is_some() {}
+    }
+
+    /// `reduce_stepwise`, keeping every action along with the number it
+    /// left behind, to compare against the puzzle's walkthrough.
+    pub fn reduce_traced(&mut self) -> Vec<Step> {
+        let mut steps = vec![];
+
+        while let Some(action) = self.step() {
+            steps.push(Step {
+                action,
+                number: SnailNumber::from(&*self),
+            });
+        }
+
+        steps
+    }
+
+    // Explodes come before splits, and only one thing happens at a time.
+    fn step(&mut self) -> Option<Action> {
+        self.explode_once().or_else(|| self.split_once())
+    }
+
+    fn split_once(&mut self) -> Option<Action> {
+        if let Some((i, &v)) = self.values.iter().enumerate().find(|(_i, v)| **v >= 10) {
+            let left = ((v as f32) / 2.0).floor() as usize;
+            let right = ((v as f32) / 2.0).ceil() as usize;
+            let depth = self.depths[i] + 1;
+            self.values[i] = right;
+            self.values.insert(i, left);
+            self.depths[i] = depth;
+            self.depths.insert(i, depth);
+            Some(Action::Split { index: i, value: v })
+        } else {
+            None
+        }
+    }
+
+    fn explode_once(&mut self) -> Option<Action> {
+        let mut explode_i = None;
+        for (i, depth_chunk) in self.depths.windows(2).enumerate() {
+            assert!(depth_chunk.len() == 2);
+
+            if depth_chunk[0] >= 5 && (depth_chunk[0] == depth_chunk[1]) {
+                explode_i = Some(i);
+                break;
+            }
+        }
+
+        if let Some(i) = explode_i {
+            let lval = self.values[i];
+            let rval = self.values[i + 1];
+            let pair = (lval, rval);
+
+            if i == 0 {
+                self.values[i + 2] += rval;
+
+                self.values.remove(i + 1);
+                self.depths.remove(i + 1);
+                self.values[i] = 0;
+                self.depths[i] -= 1;
+                return Some(Action::Explode { index: i, pair });
+            }
+            if i + 1 == self.values.len() - 1 {
+                self.values[i - 1] += lval;
+
+                self.values.remove(i + 1);
+                self.depths.remove(i + 1);
+                self.values[i] = 0;
+                self.depths[i] -= 1;
+                return Some(Action::Explode { index: i, pair });
+            }
+
+            self.values[i - 1] += lval;
+            self.values[i + 2] += rval;
+
+            self.values.remove(i + 1);
+            self.depths.remove(i + 1);
+            self.values[i] = 0;
+            self.depths[i] -= 1;
+
+            Some(Action::Explode { index: i, pair })
+        } else {
+            None
+        }
+    }
+}
+
+/// One thing that happened while reducing. `index` counts regular numbers
+/// from the left, starting at 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// The pair whose left number was at `index` exploded.
+    Explode { index: usize, pair: (usize, usize) },
+    /// `value` at `index` split into a pair.
+    Split { index: usize, value: usize },
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Explode { index, pair } => {
+                write!(f, "explode [{},{}] at {}", pair.0, pair.1, index)
+            }
+            Action::Split { index, value } => write!(f, "split {} at {}", value, index),
+        }
+    }
+}
+
+/// An action taken while reducing and what the number looked like after.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub action: Action,
+    pub number: SnailNumber,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "after {}: {}", self.action, self.number)
+    }
+}
+
+// Adding snailfish numbers always reduces the result.
+impl AddAssign<&FlatNumbers> for FlatNumbers {
+    fn add_assign(&mut self, other: &FlatNumbers) {
+        *self = self.concat(other);
+        self.reduce()
+    }
+}
+
+impl AddAssign for FlatNumbers {
+    fn add_assign(&mut self, other: FlatNumbers) {
+        *self += &other;
+    }
+}
+
+impl Add<&FlatNumbers> for FlatNumbers {
+    type Output = FlatNumbers;
+
+    fn add(mut self, other: &FlatNumbers) -> FlatNumbers {
+        self += other;
+        self
+    }
+}
+
+impl Add for FlatNumbers {
+    type Output = FlatNumbers;
+
+    fn add(self, other: FlatNumbers) -> FlatNumbers {
+        self + &other
+    }
+}
+
+impl Add for &FlatNumbers {
+    type Output = FlatNumbers;
+
+    fn add(self, other: &FlatNumbers) -> FlatNumbers {
+        self.clone() + other
+    }
+}
+
+// There's no snailfish zero, so summing no numbers gives `None`. That's
+// also what a sum of `Result`s sees when the first one is an error.
+impl Sum<FlatNumbers> for Option<FlatNumbers> {
+    fn sum<I: Iterator<Item = FlatNumbers>>(mut numbers: I) -> Self {
+        let first = numbers.next()?;
+        Some(numbers.fold(first, |sum, number| sum + number))
+    }
+}
+
+impl<'a> Sum<&'a FlatNumbers> for Option<FlatNumbers> {
+    fn sum<I: Iterator<Item = &'a FlatNumbers>>(mut numbers: I) -> Self {
+        let first = numbers.next()?.clone();
+        Some(numbers.fold(first, |sum, number| sum + number))
+    }
+}
+
+pub fn lex_flat(line: &str) -> Result<FlatNumbers, ParseError> {
+    let mut flat_numbers = FlatNumbers {
+        values: vec![],
+        depths: vec![],
+    };
+
+    let error = |i: usize, reason: &str| Err(ParseError::at(Day18::DAY, line, &line[i..], reason));
+
+    // how many elements of each open pair have been closed off with a `,`
+    let mut pairs: Vec<u8> = vec![];
+    let mut expect_element = true;
+    let mut number_start = None;
+
+    for (i, b) in line.bytes().enumerate() {
+        if let (Some(start), b',' | b']') = (number_start, b) {
+            let value = parse_field(Day18::DAY, line, &line[start..i])?;
+            number_start = None;
+
+            flat_numbers.values.push(value);
+            flat_numbers.depths.push(pairs.len());
+        }
+
+        match b {
+            b'[' if expect_element => pairs.push(0),
+            b'0'..=b'9' if pairs.is_empty() => return error(i, "expected `[`"),
+            b'0'..=b'9' if expect_element || number_start.is_some() => {
+                number_start.get_or_insert(i);
+                expect_element = false;
+                continue;
+            }
+            b'[' | b'0'..=b'9' => return error(i, "expected `,` or `]`"),
+            _ if expect_element => return error(i, "expected a number or `[`"),
+            b',' => match pairs.last_mut() {
+                Some(closed @ 0) => *closed = 1,
+                _ => return error(i, "a pair has only two elements"),
+            },
+            b']' => match pairs.pop() {
+                Some(1) => {
+                    expect_element = false;
+                    if pairs.is_empty() && i + 1 < line.len() {
+                        return error(i + 1, "expected the end of the line");
+                    }
+                    continue;
+                }
+                _ => return error(i, "a pair needs two elements"),
+            },
+            _ => return error(i, "expected `,` or `]`"),
+        }
+
+        expect_element = true;
+    }
+
+    if !pairs.is_empty() || flat_numbers.values.is_empty() {
+        return Err(ParseError::new(
+            Day18::DAY,
+            line,
+            line.chars().count() + 1,
+            "expected a closing `]`",
+        ));
+    }
+
+    Ok(flat_numbers)
+}
+
+/// A snailfish number as the tree it's written as, for printing and for
+/// checking against the worked examples. The arithmetic is done on
+/// `FlatNumbers`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnailNumber {
+    Regular(usize),
+    Pair(Box<SnailNumber>, Box<SnailNumber>),
+}
+
+impl SnailNumber {
+    pub fn pair(left: SnailNumber, right: SnailNumber) -> Self {
+        SnailNumber::Pair(Box::new(left), Box::new(right))
+    }
+
+    pub fn magnitude(&self) -> usize {
+        match self {
+            SnailNumber::Regular(value) => *value,
+            SnailNumber::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+
+    // Builds whatever starts at `values[*i]`, which is nested in `depth`
+    // pairs, and moves `i` past it.
+    fn from_flat(flat: &FlatNumbers, i: &mut usize, depth: usize) -> Self {
+        if flat.depths[*i] == depth {
+            *i += 1;
+            SnailNumber::Regular(flat.values[*i - 1])
+        } else {
+            let left = Self::from_flat(flat, i, depth + 1);
+            let right = Self::from_flat(flat, i, depth + 1);
+            SnailNumber::pair(left, right)
+        }
+    }
+
+    fn flatten(&self, flat: &mut FlatNumbers, depth: usize) {
+        match self {
+            SnailNumber::Regular(value) => {
+                flat.values.push(*value);
+                flat.depths.push(depth);
+            }
+            SnailNumber::Pair(left, right) => {
+                left.flatten(flat, depth + 1);
+                right.flatten(flat, depth + 1);
+            }
+        }
+    }
+}
+
+impl From<&FlatNumbers> for SnailNumber {
+    fn from(flat: &FlatNumbers) -> Self {
+        SnailNumber::from_flat(flat, &mut 0, 0)
+    }
+}
+
+impl From<&SnailNumber> for FlatNumbers {
+    fn from(number: &SnailNumber) -> Self {
+        let mut flat = FlatNumbers {
+            values: vec![],
+            depths: vec![],
+        };
+
+        number.flatten(&mut flat, 0);
+
+        flat
+    }
+}
+
+impl FromStr for SnailNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(SnailNumber::from(&lex_flat(s)?))
+    }
+}
+
+impl Display for SnailNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnailNumber::Regular(value) => write!(f, "{}", value),
+            SnailNumber::Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+/// The two numbers that add up to the largest magnitude, as indexes into the
+/// homework, in the order they're added.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BestPair {
+    pub left: usize,
+    pub right: usize,
+    pub magnitude: usize,
+}
+
+/// Tries adding every ordered pair of different numbers, shared out across
+/// `threads` threads. On a tie the first pair wins, however many threads
+/// there are.
+pub fn largest_pair(numbers: &[FlatNumbers], threads: usize) -> Option<BestPair> {
+    let threads = threads.clamp(1, numbers.len().max(1));
+
+    // each left number is as much work as any other, so every thread takes
+    // every `threads`th one
+    let search = |first: usize| {
+        let mut best: Option<BestPair> = None;
+
+        for left in (first..numbers.len()).step_by(threads) {
+            for right in 0..numbers.len() {
+                if left == right {
+                    continue;
+                }
+
+                let mut sum = numbers[left].concat(&numbers[right]);
+                sum.reduce();
+                let magnitude = sum.magnitude();
+
+                if best.is_none_or(|best| magnitude > best.magnitude) {
+                    best = Some(BestPair {
+                        left,
+                        right,
+                        magnitude,
+                    });
+                }
+            }
+        }
+
+        best
+    };
+
+    let bests: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|first| scope.spawn(move || search(first)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    bests
+        .into_iter()
+        .flatten()
+        .min_by_key(|best| (std::cmp::Reverse(best.magnitude), best.left, best.right))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: usize = 18;
+
+    type Input = Vec<FlatNumbers>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let numbers = parse_lines(input, lex_flat)?;
+
+        if numbers.is_empty() {
+            return Err(ParseError::empty(Self::DAY));
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input) -> Self::Part1 {
+        // parse won't give back no numbers, which would add up to nothing
+        numbers
+            .iter()
+            .sum::<Option<FlatNumbers>>()
+            .map_or(0, |sum| sum.magnitude())
+    }
+
+    fn part2(numbers: &Self::Input) -> Self::Part2 {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+
+        largest_pair(numbers, threads).map_or(0, |best| best.magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOMEWORK: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    #[test]
+    fn explode() {
+        let input = "[[[[[9,8],1],2],3],4]";
+        let expected = "[[[[0,9],2],3],4]";
+        let mut input_number = lex_flat(input).unwrap();
+        let expected_number = lex_flat(expected).unwrap();
+
+        input_number.explode_once();
+
+        assert_eq!(input_number, expected_number);
+
+        let input = "[7,[6,[5,[4,[3,2]]]]]";
+        let expected = "[7,[6,[5,[7,0]]]]";
+        let mut input_number = lex_flat(input).unwrap();
+        let expected_number = lex_flat(expected).unwrap();
+
+        input_number.explode_once();
+
+        assert_eq!(input_number, expected_number);
+
+        let input = "[[6,[5,[4,[3,2]]]],1]";
+        let expected = "[[6,[5,[7,0]]],3]";
+        let mut input_number = lex_flat(input).unwrap();
+        let expected_number = lex_flat(expected).unwrap();
+
+        input_number.explode_once();
+
+        assert_eq!(input_number, expected_number);
+
+        let input = "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]";
+        let expected = "[[3,[2,[8,0]]],[9,[5,[7,0]]]]";
+        let mut input_number = lex_flat(input).unwrap();
+        let expected_number = lex_flat(expected).unwrap();
+
+        input_number.explode_once();
+
+        assert_eq!(input_number, expected_number);
+    }
+
+    #[test]
+    fn split() {
+        let input = "[[[[0,7],4],[15,[0,13]]],[1,1]]";
+        let expected = "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]";
+        let mut input_number = lex_flat(input).unwrap();
+        let expected_number = lex_flat(expected).unwrap();
+
+        input_number.split_once();
+
+        assert_eq!(input_number, expected_number);
+
+        let expected = "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]";
+        let expected_number = lex_flat(expected).unwrap();
+
+        input_number.split_once();
+
+        assert_eq!(input_number, expected_number);
+    }
+
+    #[test]
+    fn flatten() {
+        let input = "[[[[[9,8],1],2],3],4]";
+        let input_number = lex_flat(input).unwrap();
+
+        assert_eq!(
+            input_number,
+            FlatNumbers {
+                values: vec![9, 8, 1, 2, 3, 4],
+                depths: vec![5, 5, 4, 3, 2, 1]
+            }
+        );
+
+        let input = "[[3,[2,[8,0]]],[9,[5,[7,0]]]]";
+        let input_number = lex_flat(input).unwrap();
+        assert_eq!(
+            input_number,
+            FlatNumbers {
+                values: vec![3, 2, 8, 0, 9, 5, 7, 0],
+                depths: vec![2, 3, 4, 4, 2, 3, 4, 4]
+            }
+        );
+
+        let input = "[[[[0,7],4],[15,[0,13]]],[1,1]]";
+        let input_number = lex_flat(input).unwrap();
+        assert_eq!(
+            input_number,
+            FlatNumbers {
+                values: vec![0, 7, 4, 15, 0, 13, 1, 1],
+                depths: vec![4, 4, 3, 3, 4, 4, 2, 2]
+            }
+        )
+    }
+
+    #[test]
+    fn add() {
+        let input = "[[[[4,3],4],4],[7,[[8,4],9]]]";
+        let mut input_number = lex_flat(input).unwrap();
+        let add = "[1,1]";
+        let add_number = lex_flat(add).unwrap();
+        let expected = "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]";
+        let expected_number = lex_flat(expected).unwrap();
+
+        input_number += &add_number;
+
+        assert_eq!(input_number, expected_number);
+    }
+
+    #[test]
+    fn magnitude() {
+        let input = "[9,1]";
+        let input_number = lex_flat(input).unwrap();
+        let magnitude = input_number.magnitude();
+        assert_eq!(magnitude, 29);
+
+        let input = "[1,9]";
+        let input_number = lex_flat(input).unwrap();
+        let magnitude = input_number.magnitude();
+        assert_eq!(magnitude, 21);
+
+        let input = "[[9,1],[1,9]]";
+        let input_number = lex_flat(input).unwrap();
+        let magnitude = input_number.magnitude();
+        assert_eq!(magnitude, 129);
+
+        let input = "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]";
+        let input_number = lex_flat(input).unwrap();
+        let magnitude = input_number.magnitude();
+        assert_eq!(magnitude, 3488);
+    }
+
+    #[test]
+    fn lex_errors() {
+        let e = lex_flat("[[1,2],x]").unwrap_err();
+        assert_eq!(e.column, 8);
+
+        assert!(lex_flat("[1,2,3]").is_err());
+        assert!(lex_flat("[1,[2]]").is_err());
+        assert!(lex_flat("[1,2").is_err());
+        assert!(lex_flat("[1,2]]").is_err());
+
+        let e = Day18::parse("[1,2]\n[3;4]").unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (18, 2, 3));
+    }
+
+    #[test]
+    fn tree() {
+        let input = "[[3,[2,[8,0]]],[9,[5,[7,0]]]]";
+        let number: SnailNumber = input.parse().unwrap();
+
+        assert_eq!(number.to_string(), input);
+        assert_eq!(FlatNumbers::from(&number), lex_flat(input).unwrap());
+
+        let one = SnailNumber::Regular(1);
+        assert_eq!(
+            "[1,[1,1]]".parse::<SnailNumber>().unwrap(),
+            SnailNumber::pair(one.clone(), SnailNumber::pair(one.clone(), one))
+        );
+
+        assert!("[1,2".parse::<SnailNumber>().is_err());
+    }
+
+    #[test]
+    fn trace() {
+        let a = lex_flat("[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap();
+        let b = lex_flat("[1,1]").unwrap();
+
+        let steps = a.concat(&b).reduce_traced();
+        let numbers: Vec<_> = steps.iter().map(|step| step.number.to_string()).collect();
+
+        // straight from the puzzle
+        assert_eq!(
+            numbers,
+            [
+                "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+                "[[[[0,7],4],[15,[0,13]]],[1,1]]",
+                "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+                "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+                "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            ]
+        );
+
+        assert_eq!(
+            steps[0].action,
+            Action::Explode {
+                index: 0,
+                pair: (4, 3)
+            }
+        );
+        assert_eq!(
+            steps[2].to_string(),
+            "after split 15 at 3: [[[[0,7],4],[[7,8],[0,13]]],[1,1]]"
+        );
+    }
+
+    #[test]
+    fn single_pass_reduce() {
+        let numbers = Day18::parse(HOMEWORK).unwrap();
+
+        for a in &numbers {
+            for b in &numbers {
+                let mut single_pass = a.concat(b);
+                let mut stepwise = single_pass.clone();
+
+                single_pass.reduce();
+                stepwise.reduce_stepwise();
+
+                assert_eq!(single_pass, stepwise);
+            }
+        }
+    }
+
+    #[test]
+    fn largest() {
+        let numbers = Day18::parse(HOMEWORK).unwrap();
+
+        let expected = BestPair {
+            left: 8,
+            right: 0,
+            magnitude: 3993,
+        };
+
+        for threads in [1, 3, 100] {
+            assert_eq!(largest_pair(&numbers, threads), Some(expected));
+        }
+
+        assert_eq!(largest_pair(&numbers[..1], 4), None);
+    }
+
+    #[test]
+    fn homework() {
+        let numbers = Day18::parse(HOMEWORK).unwrap();
+        let sum = SnailNumber::from(&numbers.iter().sum::<Option<_>>().unwrap());
+        assert_eq!(
+            sum.to_string(),
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+        );
+        assert_eq!(sum.magnitude(), 4140);
+        assert_eq!(Day18::part1(&numbers), 4140);
+        assert_eq!(Day18::part2(&numbers), 3993);
+    }
+
+    #[test]
+    fn sum_results() {
+        let sum = |input: &str| input.lines().map(lex_flat).sum::<Result<Option<_>, _>>();
+
+        let total = sum("[1,2]\n[[3,4],5]").unwrap().unwrap();
+        assert_eq!(SnailNumber::from(&total).to_string(), "[[1,2],[[3,4],5]]");
+
+        // the bad line stops the sum before it's seen anything
+        let e = sum("[1,2\n[3,4]").unwrap_err();
+        assert_eq!(e.line, 1);
+        assert!(sum("[1,2]\n[3,4").is_err());
+
+        assert_eq!(sum(""), Ok(None));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Day18::parse("").is_err());
+    }
+}
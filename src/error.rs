@@ -0,0 +1,116 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Why a puzzle input could not be parsed, and where. `line` and `column`
+/// are 1-based and `text` is the whole line the problem was found on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: usize,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// An error at `column` of `text`. Parsers that only ever see one line
+    /// leave it on line 1 and let their caller move it with `on_line`.
+    pub fn new(day: usize, text: &str, column: usize, reason: impl Into<String>) -> Self {
+        Self {
+            day,
+            line: 1,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// An error about `part`, which is a slice of `text`; the column is
+    /// wherever `part` starts.
+    pub fn at(day: usize, text: &str, part: &str, reason: impl Into<String>) -> Self {
+        Self::new(day, text, column_of(text, part), reason)
+    }
+
+    /// For a day that needs at least something to work with.
+    pub fn empty(day: usize) -> Self {
+        Self::new(day, "", 1, "the input is empty")
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "day {} input, line {}, column {}: {}",
+            self.day, self.line, self.column, self.reason
+        )?;
+        writeln!(f, "    {}", self.text)?;
+        write!(f, "    {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `part`, a slice of the line `text`, reporting where it sits in
+/// the line if it isn't a valid `T`.
+pub fn parse_field<T>(day: usize, text: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    part.parse()
+        .map_err(|e| ParseError::at(day, text, part, format!("`{}`: {}", part, e)))
+}
+
+/// Parses every line of `input` with `parse_line`, numbering the line in
+/// any error it returns.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+// Falls back to the first column if `part` doesn't point into `text`.
+fn column_of(text: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+
+    match text.get(..offset) {
+        Some(before) => before.chars().count() + 1,
+        None => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_column() {
+        let line = "forward 5x";
+        let e = parse_field::<i32>(2, line, &line[8..]).unwrap_err();
+
+        assert_eq!(e.column, 9);
+        assert_eq!(e.line, 1);
+        assert_eq!(e.on_line(4).line, 4);
+    }
+
+    #[test]
+    fn display() {
+        let e = ParseError::new(10, "{([x", 4, "unexpected character 'x'").on_line(2);
+
+        assert_eq!(
+            e.to_string(),
+            "day 10 input, line 2, column 4: unexpected character 'x'\n    {([x\n       ^"
+        );
+    }
+}
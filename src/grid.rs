@@ -0,0 +1,270 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+
+const ORTHOGONAL: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// A rectangular grid stored row by row. Cells are addressed either by
+/// `(x, y)` or by their index into that flat storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn i_to_xy(&self, i: usize) -> (usize, usize) {
+        (i % self.width, i / self.width)
+    }
+
+    pub fn xy_to_i(&self, x: usize, y: usize) -> usize {
+        x + self.width * y
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[self.xy_to_i(x, y)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            let i = self.xy_to_i(x, y);
+            Some(&mut self.cells[i])
+        } else {
+            None
+        }
+    }
+
+    /// Indexes of the cells above, below, left and right of `i` that are
+    /// inside the grid.
+    pub fn neighbours4(&self, i: usize) -> impl Iterator<Item = usize> {
+        neighbours4(self.width, self.height, i)
+    }
+
+    /// Like `neighbours4`, but including the diagonals.
+    pub fn neighbours8(&self, i: usize) -> impl Iterator<Item = usize> {
+        neighbours(self.width, self.height, i, &ALL_AROUND)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+}
+
+impl Grid<u8> {
+    /// One digit per cell, one row per line. Every row has to be as wide
+    /// as the first.
+    pub fn from_digits(day: usize, input: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let error = |x: usize, reason: String| {
+                Err(ParseError::new(day, line, x + 1, reason).on_line(y + 1))
+            };
+
+            let mut row_width = 0;
+
+            for (x, c) in line.chars().enumerate() {
+                match c.to_digit(10) {
+                    Some(digit) => cells.push(digit as u8),
+                    None => return error(x, format!("expected a digit, found {:?}", c)),
+                }
+
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let reason = format!("expected {} digits like the first row", width);
+                    return error(row_width.min(width), reason);
+                }
+                _ => (),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+/// `Grid::neighbours4` for anything laid out like a grid without being one,
+/// such as a view computed on the fly.
+pub fn neighbours4(width: usize, height: usize, i: usize) -> impl Iterator<Item = usize> {
+    neighbours(width, height, i, &ORTHOGONAL)
+}
+
+fn neighbours(
+    width: usize,
+    height: usize,
+    i: usize,
+    offsets: &'static [(isize, isize)],
+) -> impl Iterator<Item = usize> {
+    let (x, y) = (i % width, i / width);
+
+    offsets.iter().filter_map(move |&(dx, dy)| {
+        let x = x.checked_add_signed(dx).filter(|x| *x < width)?;
+        let y = y.checked_add_signed(dy).filter(|y| *y < height)?;
+
+        Some(x + width * y)
+    })
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.cells[i]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).expect("coordinates outside the grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y).expect("coordinates outside the grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<u8> {
+        Grid::from_digits(0, "123\n456").unwrap()
+    }
+
+    #[test]
+    fn coordinates() {
+        let grid = sample();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.i_to_xy(4), (1, 1));
+        assert_eq!(grid.xy_to_i(2, 1), 5);
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get(3, 0), None);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = sample();
+
+        let mut corner: Vec<_> = grid.neighbours4(0).collect();
+        corner.sort_unstable();
+        assert_eq!(corner, [1, 3]);
+
+        let mut middle: Vec<_> = grid.neighbours8(1).collect();
+        middle.sort_unstable();
+        assert_eq!(middle, [0, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn views() {
+        let grid = sample();
+
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn ragged() {
+        let e = Grid::from_digits(9, "123\n45\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+
+        let e = Grid::from_digits(9, "123\n4x6").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+    }
+}
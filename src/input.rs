@@ -0,0 +1,141 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// `<day>.txt` in the inputs directory
+    Day,
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    /// `None` means the day's default input, `-` means stdin,
+    /// and anything else is a path.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Day,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `$AOC_INPUTS_DIR` if it is set, otherwise this crate's `inputs/`,
+    /// so the default doesn't depend on the working directory.
+    pub fn from_env() -> Self {
+        match std::env::var_os(INPUTS_DIR_VAR) {
+            Some(dir) => Self::new(dir),
+            None => Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn day_path(&self, day: usize) -> PathBuf {
+        self.dir.join(format!("{}.txt", day))
+    }
+
+    pub fn read(&self, day: usize, source: &Source) -> Result<String, InputError> {
+        match source {
+            Source::Day => read_file(&self.day_path(day)),
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut s = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut s)
+                    .map_err(|e| InputError {
+                        origin: "stdin".to_string(),
+                        error: e,
+                    })?;
+                Ok(s)
+            }
+            Source::Inline(s) => Ok(s.clone()),
+        }
+    }
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|e| InputError {
+        origin: path.display().to_string(),
+        error: e,
+    })
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    origin: String,
+    error: std::io::Error,
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not read input from {}: {}",
+            self.origin, self.error
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline() {
+        let inputs = Inputs::new("does-not-exist");
+        let s = inputs.read(1, &Source::Inline("199\n200".to_string()));
+        assert_eq!(s.unwrap(), "199\n200");
+    }
+
+    #[test]
+    fn day_path() {
+        let inputs = Inputs::new("/some/where");
+        assert_eq!(inputs.day_path(15), PathBuf::from("/some/where/15.txt"));
+    }
+
+    #[test]
+    fn missing_file() {
+        let inputs = Inputs::new("does-not-exist");
+        let e = inputs.read(3, &Source::Day).unwrap_err();
+        assert!(e.to_string().contains("3.txt"));
+    }
+
+    #[test]
+    fn from_arg() {
+        assert_eq!(Source::from_arg(None), Source::Day);
+        assert_eq!(Source::from_arg(Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("mine.txt")),
+            Source::File(PathBuf::from("mine.txt"))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+pub mod bigint;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod pathfinding;
+pub mod solution;
+
+use error::ParseError;
+use solution::{run_part1, run_part2};
+
+pub const DAYS: usize = 18;
+
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+
+pub fn solver(day: usize, part: usize) -> Option<Solver> {
+    let solver: Solver = match (day, part) {
+        (1, 1) => run_part1::<day01::Day1>,
+        (1, 2) => run_part2::<day01::Day1>,
+        (2, 1) => run_part1::<day02::Day2>,
+        (2, 2) => run_part2::<day02::Day2>,
+        (3, 1) => run_part1::<day03::Day3>,
+        (3, 2) => run_part2::<day03::Day3>,
+        (4, 1) => run_part1::<day04::Day4>,
+        (4, 2) => run_part2::<day04::Day4>,
+        (5, 1) => run_part1::<day05::Day5>,
+        (5, 2) => run_part2::<day05::Day5>,
+        (6, 1) => run_part1::<day06::Day6>,
+        (6, 2) => run_part2::<day06::Day6>,
+        (7, 1) => run_part1::<day07::Day7>,
+        (7, 2) => run_part2::<day07::Day7>,
+        (8, 1) => run_part1::<day08::Day8>,
+        (8, 2) => run_part2::<day08::Day8>,
+        (9, 1) => run_part1::<day09::Day9>,
+        (9, 2) => run_part2::<day09::Day9>,
+        (10, 1) => run_part1::<day10::Day10>,
+        (10, 2) => run_part2::<day10::Day10>,
+        (11, 1) => run_part1::<day11::Day11>,
+        (11, 2) => run_part2::<day11::Day11>,
+        (12, 1) => run_part1::<day12::Day12>,
+        (12, 2) => run_part2::<day12::Day12>,
+        (13, 1) => run_part1::<day13::Day13>,
+        (13, 2) => run_part2::<day13::Day13>,
+        (14, 1) => run_part1::<day14::Day14>,
+        (14, 2) => run_part2::<day14::Day14>,
+        (15, 1) => run_part1::<day15::Day15>,
+        (15, 2) => run_part2::<day15::Day15>,
+        (16, 1) => run_part1::<day16::Day16>,
+        (16, 2) => run_part2::<day16::Day16>,
+        (17, 1) => run_part1::<day17::Day17>,
+        (17, 2) => run_part2::<day17::Day17>,
+        (18, 1) => run_part1::<day18::Day18>,
+        (18, 2) => run_part2::<day18::Day18>,
+        _ => return None,
+    };
+
+    Some(solver)
+}
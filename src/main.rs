@@ -1,3 +1,140 @@
+use std::time::Instant;
+
+use aoc::input::{Inputs, Source};
+
+const USAGE: &str = "usage:
+    aoc run <day> [<part>] [--input <path> | --input - | --inline <text>] [--inputs-dir <dir>]
+    aoc run --all [--inputs-dir <dir>]";
+
+struct Args {
+    to_run: Vec<(usize, usize)>,
+    source: Source,
+    inputs: Inputs,
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+
+    let Args {
+        to_run,
+        source,
+        inputs,
+    } = match parse_args(&args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    // Both parts of a day share one read, which matters for stdin.
+    let mut input: Option<(usize, String)> = None;
+
+    for (day, part) in to_run {
+        let text = match input {
+            Some((input_day, ref text)) if input_day == day => text,
+            _ => match inputs.read(day, &source) {
+                Ok(text) => &input.insert((day, text)).1,
+                Err(e) => {
+                    eprintln!("day {} part {}: {}", day, part, e);
+                    std::process::exit(1);
+                }
+            },
+        };
+
+        run(day, part, text);
+    }
+}
+
+fn parse_args(args: &[&str]) -> Result<Args, String> {
+    let mut args = args.iter().copied();
+
+    if args.next() != Some("run") {
+        return Err("expected the run command".to_string());
+    }
+
+    let mut positional = vec![];
+    let mut all_days = false;
+    let mut source = Source::Day;
+    let mut inputs = Inputs::from_env();
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--all" => all_days = true,
+            "--input" => source = Source::from_arg(Some(flag_value(arg, args.next())?)),
+            "--inline" => source = Source::Inline(flag_value(arg, args.next())?.to_string()),
+            "--inputs-dir" => inputs = Inputs::new(flag_value(arg, args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {}", flag)),
+            other => positional.push(other),
+        }
+    }
+
+    let to_run = match (all_days, positional.as_slice()) {
+        (true, []) if source == Source::Day => all(),
+        (true, []) => return Err("--all always reads each day's own input".to_string()),
+        (false, [day]) => parse_day(day).map(|day| vec![(day, 1), (day, 2)])?,
+        (false, [day, part]) => vec![(parse_day(day)?, parse_part(part)?)],
+        _ => return Err("expected a day and an optional part, or --all".to_string()),
+    };
+
+    Ok(Args {
+        to_run,
+        source,
+        inputs,
+    })
+}
+
+fn flag_value<'a>(flag: &str, value: Option<&'a str>) -> Result<&'a str, String> {
+    value.ok_or_else(|| format!("{} needs a value", flag))
+}
+
+fn all() -> Vec<(usize, usize)> {
+    (1..=aoc::DAYS)
+        .flat_map(|day| [(day, 1), (day, 2)])
+        .collect()
+}
+
+fn parse_day(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(day) if (1..=aoc::DAYS).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "day must be between 1 and {}, got {}",
+            aoc::DAYS,
+            s
+        )),
+    }
+}
+
+fn parse_part(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(part) if part == 1 || part == 2 => Ok(part),
+        _ => Err(format!("part must be 1 or 2, got {}", s)),
+    }
+}
+
+fn run(day: usize, part: usize, input: &str) {
+    let solver = aoc::solver(day, part).unwrap();
+
+    let start = Instant::now();
+    let answer = match solver(input) {
+        Ok(answer) => answer,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let elapsed = start.elapsed();
+
+    if answer.contains('\n') {
+        println!(
+            "day {} part {} ({:?}):\n{}",
+            day,
+            part,
+            elapsed,
+            answer.trim_end()
+        );
+    } else {
+        println!("day {} part {} ({:?}): {}", day, part, elapsed, answer);
+    }
 }
@@ -0,0 +1,254 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+// marks the start node, which has no parent
+const NO_PARENT: usize = usize::MAX;
+
+/// The cheapest way found to a goal: its total cost and every node on it,
+/// from the start to the goal inclusive. `expanded` counts the nodes whose
+/// neighbours were looked at along the way, to compare searches by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    pub nodes: Vec<N>,
+    pub expanded: usize,
+}
+
+/// Shortest path from `start` to the first node satisfying `is_goal`.
+/// `neighbours` gives each node reachable from a node along with the cost
+/// of that step.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// Like `dijkstra`, but explores towards the goal first using `heuristic`,
+/// an estimate of the remaining cost from a node. The estimate must never
+/// be more than the real cost or the path found might not be the cheapest.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    // Nodes get dense ids as they're discovered, so the queue and the
+    // bookkeeping don't need `N: Ord` or repeated hashing.
+    let mut nodes = vec![start.clone()];
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut costs = vec![0];
+    let mut parents = vec![NO_PARENT];
+
+    // Ordered by estimated total cost, then by preferring whichever has come
+    // further, which keeps A* from fanning out across equally good nodes.
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), Reverse(0), 0))]);
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, Reverse(cost), i))) = queue.pop() {
+        // a cheaper way here was already expanded
+        if cost > costs[i] {
+            continue;
+        }
+
+        if is_goal(&nodes[i]) {
+            return Some(Path {
+                cost,
+                nodes: backtrack(&parents, i, |i| nodes[i].clone()),
+                expanded,
+            });
+        }
+
+        expanded += 1;
+
+        let node = nodes[i].clone();
+
+        for (neighbour, step_cost) in neighbours(&node) {
+            let j = match ids.entry(neighbour) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    nodes.push(entry.key().clone());
+                    costs.push(usize::MAX);
+                    parents.push(NO_PARENT);
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+
+            let alt = cost + step_cost;
+
+            if alt < costs[j] {
+                costs[j] = alt;
+                parents[j] = i;
+                queue.push(Reverse((alt + heuristic(&nodes[j]), Reverse(alt), j)));
+            }
+        }
+    }
+
+    None
+}
+
+/// `dijkstra` for graphs whose nodes are already numbered `0..len`, like
+/// the cells of a grid. Costs and parents live in plain vectors of `len`
+/// entries, with no hashing and no copy of each node.
+pub fn dijkstra_indexed<I>(
+    len: usize,
+    start: usize,
+    neighbours: impl FnMut(usize) -> I,
+    is_goal: impl FnMut(usize) -> bool,
+) -> Option<Path<usize>>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    astar_indexed(len, start, neighbours, |_| 0, is_goal)
+}
+
+/// `astar` for graphs whose nodes are already numbered `0..len`.
+pub fn astar_indexed<I>(
+    len: usize,
+    start: usize,
+    mut neighbours: impl FnMut(usize) -> I,
+    mut heuristic: impl FnMut(usize) -> usize,
+    mut is_goal: impl FnMut(usize) -> bool,
+) -> Option<Path<usize>>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut costs = vec![usize::MAX; len];
+    let mut parents = vec![NO_PARENT; len];
+    costs[start] = 0;
+
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), Reverse(0), start))]);
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, Reverse(cost), i))) = queue.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+
+        if is_goal(i) {
+            return Some(Path {
+                cost,
+                nodes: backtrack(&parents, i, |i| i),
+                expanded,
+            });
+        }
+
+        expanded += 1;
+
+        for (j, step_cost) in neighbours(i) {
+            let alt = cost + step_cost;
+
+            if alt < costs[j] {
+                costs[j] = alt;
+                parents[j] = i;
+                queue.push(Reverse((alt + heuristic(j), Reverse(alt), j)));
+            }
+        }
+    }
+
+    None
+}
+
+fn backtrack<N>(parents: &[usize], goal: usize, mut node: impl FnMut(usize) -> N) -> Vec<N> {
+    let mut path = vec![];
+    let mut i = goal;
+
+    while i != NO_PARENT {
+        path.push(node(i));
+        i = parents[i];
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -1-> b -1-> c -1-> d, with a 5 shortcut from a to d and a dead end at e
+    fn edges(node: &char) -> Vec<(char, usize)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5), ('e', 1)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let path = dijkstra('a', edges, |node| *node == 'd').unwrap();
+
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, ['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let path = dijkstra('a', edges, |node| *node == 'a').unwrap();
+
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.nodes, ['a']);
+        assert_eq!(path.expanded, 0);
+    }
+
+    #[test]
+    fn unreachable() {
+        assert_eq!(dijkstra('b', edges, |node| *node == 'e'), None);
+    }
+
+    #[test]
+    fn astar_agrees() {
+        // walking a 20x20 open grid, where every step costs 1
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|(x, y)| (0..20).contains(x) && (0..20).contains(y))
+                .map(|node| (node, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| ((19 - x) + (19 - y)) as usize;
+        let is_goal = |node: &(i32, i32)| *node == (19, 19);
+
+        let plain = dijkstra((0, 0), neighbours, is_goal).unwrap();
+        let guided = astar((0, 0), neighbours, manhattan, is_goal).unwrap();
+
+        assert_eq!(plain.cost, 38);
+        assert_eq!(guided.cost, 38);
+        assert_eq!(guided.nodes.len(), 39);
+        assert!(guided.expanded < plain.expanded);
+    }
+
+    #[test]
+    fn indexed_agrees() {
+        let index = |node: char| node as usize - 'a' as usize;
+        let neighbours = |i: usize| {
+            edges(&char::from(b'a' + i as u8))
+                .into_iter()
+                .map(move |(node, cost)| (index(node), cost))
+        };
+
+        let path = dijkstra_indexed(5, 0, neighbours, |i| i == index('d')).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, [0, 1, 2, 3]);
+
+        let path = astar_indexed(5, 0, neighbours, |_| 0, |i| i == 0).unwrap();
+        assert_eq!((path.cost, path.expanded), (0, 0));
+
+        assert_eq!(
+            dijkstra_indexed(5, 1, neighbours, |i| i == index('e')),
+            None
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use crate::error::ParseError;
+
+/// A day's puzzle: `parse` turns the raw input into `Input` once,
+/// and each part computes its answer from that.
+pub trait Solution {
+    const DAY: usize;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+pub fn run_part1<S: Solution>(input: &str) -> Result<String, ParseError> {
+    let input = S::parse(input)?;
+    Ok(S::part1(&input).to_string())
+}
+
+pub fn run_part2<S: Solution>(input: &str) -> Result<String, ParseError> {
+    let input = S::parse(input)?;
+    Ok(S::part2(&input).to_string())
+}
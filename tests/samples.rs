@@ -0,0 +1,89 @@
+// The worked examples from each puzzle's description, run through the
+// same entry point as the `aoc` binary.
+
+fn solve(day: usize, part: usize, input: &str) -> String {
+    let solver = aoc::solver(day, part).unwrap();
+
+    solver(input).unwrap()
+}
+
+#[test]
+fn day01() {
+    let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+    assert_eq!(solve(1, 1, input), "7");
+    assert_eq!(solve(1, 2, input), "5");
+}
+
+#[test]
+fn day02() {
+    let input = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+    assert_eq!(solve(2, 1, input), "150");
+    assert_eq!(solve(2, 2, input), "900");
+}
+
+#[test]
+fn day05() {
+    let input = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    assert_eq!(solve(5, 1, input), "5");
+    assert_eq!(solve(5, 2, input), "12");
+}
+
+#[test]
+fn day06() {
+    let input = "3,4,3,1,2";
+
+    assert_eq!(solve(6, 1, input), "5934");
+    assert_eq!(solve(6, 2, input), "26984457539");
+}
+
+#[test]
+fn day07() {
+    let input = "16,1,2,0,4,2,7,1,2,14";
+
+    assert_eq!(solve(7, 1, input), "37");
+    assert_eq!(solve(7, 2, input), "168");
+}
+
+#[test]
+fn day10() {
+    let input = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    assert_eq!(solve(10, 1, input), "26397");
+    assert_eq!(solve(10, 2, input), "288957");
+}
+
+#[test]
+fn day17() {
+    let input = "target area: x=20..30, y=-10..-5";
+
+    assert_eq!(solve(17, 1, input), "45");
+    assert_eq!(solve(17, 2, input), "112");
+}
+
+#[test]
+fn bad_input() {
+    let solver = aoc::solver(1, 1).unwrap();
+
+    assert!(solver("199\n2x0").is_err());
+}